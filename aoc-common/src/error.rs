@@ -0,0 +1,135 @@
+use crate::input::locate_section;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A malformed piece of puzzle input. Lines and columns count from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error in `part` of `line`, where `line` is the first line of the text being parsed.
+    ///
+    /// The column is worked out from where `part` sits within `line`; a `part` that was not
+    /// sliced from `line` points at the start of it.
+    pub fn new<M: Into<String>>(line: &str, part: &str, message: M) -> ParseError {
+        ParseError {
+            file: None,
+            line: 1,
+            column: column_of(line, part),
+            text: line.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+fn column_of(line: &str, part: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).wrapping_sub(start);
+
+    if offset <= line.len() && line.is_char_boundary(offset) {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+impl Error {
+    /// A parse error at `part` of `line`, as described by `ParseError::new`.
+    pub fn parse<M: Into<String>>(line: &str, part: &str, message: M) -> Error {
+        Error::Parse(ParseError::new(line, part, message))
+    }
+
+    /// Shifts a parse error down by `lines`, for text that was parsed apart from what precedes it.
+    pub fn offset_lines(self, lines: usize) -> Error {
+        match self {
+            Error::Parse(mut error) => {
+                error.line += lines;
+                Error::Parse(error)
+            }
+            error => error,
+        }
+    }
+
+    /// Names the file a parse error came from.
+    pub fn in_file<P: AsRef<Path>>(self, file: P) -> Error {
+        match self {
+            Error::Parse(mut error) => {
+                error.file = Some(file.as_ref().to_path_buf());
+                Error::Parse(error)
+            }
+            error => error,
+        }
+    }
+
+    /// Names the file a parse error came from, for input joined from `sections` by
+    /// `join_sections`, each read from the matching entry of `files`.
+    pub fn in_sections<P: AsRef<Path>>(self, files: &[P], sections: &[String]) -> Error {
+        match self {
+            Error::Parse(mut error) => {
+                let (section, line) = locate_section(sections, error.line);
+                error.line = line;
+                Error::Parse(error).in_file(&files[section])
+            }
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "<input>:")?,
+        }
+        writeln!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "    {}", self.text)?;
+        write!(f, "    {:>width$}", "^", width = self.column)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{}", error),
+            Error::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Error {
+        Error::Parse(error)
+    }
+}
+
+#[test]
+fn column_follows_the_offending_part () {
+    let line = "mem[8] = 11x";
+    let error = ParseError::new(line, &line[9..], "invalid number");
+
+    assert_eq!(error.column, 10);
+    assert_eq!(ParseError::new(line, "elsewhere", "oops").column, 1);
+}
@@ -0,0 +1,317 @@
+use crate::error::{Error, Result};
+use crate::input::parse_lines;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Steps to the orthogonal neighbours, clockwise from north.
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Steps to the orthogonal and diagonal neighbours, clockwise from north.
+pub const NEIGHBOURS8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A rectangle of cells stored row by row, addressed by `(x, y)` with `(0, 0)` at the top left.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A cell that is drawn as a single character in puzzle input.
+pub trait Cell: Sized {
+    /// The characters a cell may be drawn with, for error messages.
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for bool {
+    const EXPECTED: &'static str = "`#` or `.`";
+
+    fn from_char(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+/// Any character stands for itself, for grids that are only drawn.
+impl Cell for char {
+    const EXPECTED: &'static str = "a character";
+
+    fn from_char(c: char) -> Option<char> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, or `None` when they differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        let height = rows.len();
+        Some(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Builds a grid by calling `f(x, y)` for every position.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Grid<T>
+    where F: FnMut(usize, usize) -> T {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+
+        Grid { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` outside the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self.cells[y as usize * self.width + x as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y as usize * self.width + x as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(x, y)` on a grid repeated endlessly in every direction.
+    ///
+    /// Panics on an empty grid.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+
+        &self.cells[y * self.width + x]
+    }
+
+    /// The position one `step` away from `(x, y)`, if it is inside the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let (nx, ny) = (x as isize + dx, y as isize + dy);
+
+        if self.contains(nx, ny) {
+            Some((nx as usize, ny as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Positions of the orthogonal neighbours of `(x, y)` that are inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item=(usize, usize)> + '_ {
+        NEIGHBOURS4.iter().filter_map(move |step| self.step((x, y), *step))
+    }
+
+    /// Positions of the orthogonal and diagonal neighbours of `(x, y)` that are inside the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item=(usize, usize)> + '_ {
+        NEIGHBOURS8.iter().filter_map(move |step| self.step((x, y), *step))
+    }
+
+    /// Positions seen looking from `(x, y)` in steps of `direction`, nearest first, up to the edge.
+    pub fn ray(&self, x: usize, y: usize, direction: (isize, isize)) -> impl Iterator<Item=(usize, usize)> + '_ {
+        let mut position = (x, y);
+
+        std::iter::from_fn(move || {
+            position = self.step(position, direction)?;
+            Some(position)
+        })
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item=(usize, usize)> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item=&T> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        assert!(x < self.width, "column {} of a grid {} wide", x, self.width);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where F: FnMut(&T) -> U {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, self.height - 1 - x)].clone())
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| self[(self.width - 1 - x, y)].clone())
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| self[(x, self.height - 1 - y)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside a {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside a {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Maps every character of `line` to a cell, or reports the first character that is not one.
+pub fn parse_cells<T: Cell>(line: &str) -> Result<Vec<T>> {
+    if line.is_empty() {
+        return Err(Error::parse(line, line, "empty row"));
+    }
+
+    line.char_indices()
+        .map(|(i, c)| T::from_char(c).ok_or_else(|| Error::parse(line, &line[i..], format!("expected {}", T::EXPECTED))))
+        .collect()
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Grid<T>> {
+        let mut width = None;
+
+        let rows = parse_lines(input, |line| {
+            let row = parse_cells::<T>(line)?;
+            match width {
+                Some(width) if width != row.len() => Err(Error::parse(line, line, format!("expected {} cells like the first row", width))),
+                _ => {
+                    width = Some(row.len());
+                    Ok(row)
+                }
+            }
+        })?;
+
+        Ok(Grid::from_rows(rows).expect("rows are checked to match"))
+    }
+}
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            let line : String = row.iter().map(|cell| cell.to_char()).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn round_trips_character_maps () {
+    let grid : Grid<bool> = "#..\n.#.".parse().unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert!(grid[(1, 1)]);
+    assert_eq!(grid.to_string(), "#..\n.#.\n");
+}
+
+#[test]
+fn reports_bad_cells_and_ragged_rows () {
+    for (input, position) in [("#.\n.x", (2, 2)), ("#.\n.#.", (2, 1)), ("#.\n\n.#", (2, 1))] {
+        match input.parse::<Grid<bool>>() {
+            Err(Error::Parse(error)) => assert_eq!((error.line, error.column), position),
+            _ => panic!("{:?} should not parse", input),
+        }
+    }
+}
+
+#[test]
+fn wraps_and_bounds_checks () {
+    let grid = Grid::from_fn(3, 2, |x, y| x + 10 * y);
+
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(grid.get(2, 1), Some(&12));
+    assert_eq!(*grid.get_wrapping(-1, 3), 12);
+    assert_eq!(*grid.get_wrapping(7, 0), 1);
+}
+
+#[test]
+fn finds_neighbours_and_rays () {
+    let grid = Grid::from_fn(3, 3, |x, y| (x, y));
+
+    assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    assert_eq!(grid.ray(0, 0, (1, 1)).collect::<Vec<_>>(), vec![(1, 1), (2, 2)]);
+}
+
+#[test]
+fn slices_and_transforms () {
+    let grid : Grid<bool> = "##.\n...".parse().unwrap();
+
+    assert_eq!(grid.row(0), &[true, true, false]);
+    assert_eq!(grid.column(1).cloned().collect::<Vec<_>>(), vec![true, false]);
+    assert_eq!(grid.rotate_cw().to_string(), ".#\n.#\n..\n");
+    assert_eq!(grid.flip_horizontal().to_string(), ".##\n...\n");
+    assert_eq!(grid.flip_vertical().to_string(), "...\n##.\n");
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn display_round_trips (grid in (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+        proptest::collection::vec(any::<bool>(), width * height)
+            .prop_map(move |cells| Grid::from_fn(width, height, |x, y| cells[y * width + x]))
+    })) {
+        prop_assert_eq!(grid.to_string().parse::<Grid<bool>>().unwrap(), grid);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::error::{Error, Result};
+use crate::grid::{Cell, Grid};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// The colour each character of a drawn grid is painted with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    colours: Vec<(char, Rgb)>,
+    other: Rgb,
+}
+
+impl Default for Palette {
+    /// Empty squares dark, walls, trees and live cells light, empty seats blue, and a path's open
+    /// squares green and trees red. Anything else is grey.
+    fn default() -> Palette {
+        Palette {
+            colours: vec![
+                ('.', Rgb(24, 24, 24)),
+                ('#', Rgb(230, 230, 230)),
+                ('L', Rgb(70, 130, 180)),
+                ('O', Rgb(60, 180, 75)),
+                ('X', Rgb(220, 50, 50)),
+            ],
+            other: Rgb(128, 128, 128),
+        }
+    }
+}
+
+impl Palette {
+    pub fn colour(&self, c: char) -> Rgb {
+        self.colours.iter().find(|(x, _)| *x == c).map_or(self.other, |(_, colour)| *colour)
+    }
+
+    pub fn set(&mut self, c: char, colour: Rgb) {
+        self.colours.retain(|(x, _)| *x != c);
+        self.colours.push((c, colour));
+    }
+}
+
+fn parse_colour(line: &str, hex: &str) -> Result<Rgb> {
+    let channel = |i: usize| hex.get(i..i + 2).and_then(|x| u8::from_str_radix(x, 16).ok());
+
+    match (hex.len(), channel(0), channel(2), channel(4)) {
+        (6, Some(r), Some(g), Some(b)) => Ok(Rgb(r, g, b)),
+        _ => Err(Error::parse(line, hex, "expected a colour like `ff8000`")),
+    }
+}
+
+/// Reads `<char>=<rrggbb>` entries separated by commas, each replacing that character's colour in
+/// the default palette.
+impl FromStr for Palette {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Palette> {
+        let mut palette = Palette::default();
+
+        for entry in line.split(',').filter(|entry| !entry.is_empty()) {
+            let mut chars = entry.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), Some('=')) => palette.set(c, parse_colour(line, chars.as_str())?),
+                _ => return Err(Error::parse(line, entry, "expected `<char>=<rrggbb>`")),
+            }
+        }
+        Ok(palette)
+    }
+}
+
+/// A picture of a grid, three bytes per pixel, row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RgbImage {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl RgbImage {
+    /// Paints each cell of `grid` as a `scale` by `scale` square in its colour from `palette`.
+    pub fn from_grid<T: Cell>(grid: &Grid<T>, palette: &Palette, scale: usize) -> RgbImage {
+        let (width, height) = (grid.width() * scale, grid.height() * scale);
+        let mut pixels = Vec::with_capacity(width * height * 3);
+
+        for row in grid.rows() {
+            let line : Vec<u8> = row.iter()
+                .flat_map(|cell| {
+                    let Rgb(r, g, b) = palette.colour(cell.to_char());
+                    std::iter::repeat_n([r, g, b], scale).flatten()
+                })
+                .collect();
+
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        RgbImage { width, height, pixels }
+    }
+
+    /// Writes the image as a binary PPM (P6).
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels)
+    }
+
+    /// Writes the image as a PNG.
+    #[cfg(feature = "png")]
+    pub fn write_png<W: Write>(&self, out: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&self.pixels).map_err(io::Error::other)
+    }
+}
+
+#[test]
+fn paints_scaled_cells () {
+    let grid : Grid<bool> = "#.".parse().unwrap();
+    let image = RgbImage::from_grid(&grid, &Palette::default(), 2);
+
+    assert_eq!((image.width, image.height), (4, 2));
+    assert_eq!(&image.pixels[..12], &[230, 230, 230, 230, 230, 230, 24, 24, 24, 24, 24, 24]);
+    assert_eq!(&image.pixels[..12], &image.pixels[12..]);
+}
+
+#[test]
+fn writes_ppm () {
+    let grid : Grid<bool> = "#".parse().unwrap();
+    let mut out = Vec::new();
+    RgbImage::from_grid(&grid, &Palette::default(), 1).write_ppm(&mut out).unwrap();
+
+    assert_eq!(out, b"P6\n1 1\n255\n\xe6\xe6\xe6");
+}
+
+#[test]
+fn reads_palettes () {
+    let palette : Palette = "#=ff8000,@=000001".parse().unwrap();
+
+    assert_eq!(palette.colour('#'), Rgb(255, 128, 0));
+    assert_eq!(palette.colour('@'), Rgb(0, 0, 1));
+    assert_eq!(palette.colour('.'), Rgb(24, 24, 24));
+    assert!("#ff8000".parse::<Palette>().is_err());
+    assert!("#=ff80".parse::<Palette>().is_err());
+    assert!("#=gg8000".parse::<Palette>().is_err());
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn writes_png () {
+    let grid : Grid<bool> = "#.".parse().unwrap();
+    let mut out = Vec::new();
+    RgbImage::from_grid(&grid, &Palette::default(), 3).write_png(&mut out).unwrap();
+
+    let decoder = png::Decoder::new(out.as_slice());
+    let reader = decoder.read_info().unwrap();
+    assert_eq!((reader.info().width, reader.info().height), (6, 3));
+}
@@ -0,0 +1,93 @@
+use crate::error::{Error, Result};
+use std::fs::File;
+use std::io::{self, BufRead, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Reads every line of `filename`, without line terminators.
+pub fn read_lines<P>(filename: P) -> io::Result<Vec<String>>
+where P: AsRef<Path>, {
+    let file = File::open(filename)?;
+    io::BufReader::new(file).lines().collect()
+}
+
+/// Reads a whole puzzle input, where a path of `-` means standard input.
+pub fn read_input<P>(filename: P) -> io::Result<String>
+where P: AsRef<Path>, {
+    let mut input = String::new();
+
+    if filename.as_ref() == Path::new("-") {
+        io::stdin().read_to_string(&mut input)?;
+    } else {
+        File::open(filename)?.read_to_string(&mut input)?;
+    }
+    Ok(input)
+}
+
+/// Reads several input files into one puzzle input, separating them by a blank line.
+pub fn read_sections<P>(filenames: &[P]) -> io::Result<String>
+where P: AsRef<Path>, {
+    let sections = filenames.iter()
+        .map(read_input)
+        .collect::<io::Result<Vec<String>>>()?;
+
+    Ok(join_sections(&sections))
+}
+
+/// Joins several inputs into one, separating them by a blank line.
+pub fn join_sections(sections: &[String]) -> String {
+    sections.iter().map(|x| x.trim_end()).collect::<Vec<&str>>().join("\n\n")
+}
+
+/// Finds which of the `sections` given to `join_sections` holds `line` of the joined input,
+/// returning the section's index and the line number within it.
+pub fn locate_section(sections: &[String], line: usize) -> (usize, usize) {
+    let mut first_line = 1;
+
+    for (i, section) in sections.iter().enumerate() {
+        let next_first_line = first_line + section.trim_end().lines().count() + 1;
+        if line < next_first_line || i == sections.len() - 1 {
+            return (i, line + 1 - first_line);
+        }
+        first_line = next_first_line;
+    }
+    (0, line)
+}
+
+/// Splits an in-memory puzzle input into owned lines, without line terminators.
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(|x| x.to_string()).collect()
+}
+
+/// Parses every line of `input` with `parse_line`, numbering any parse error by its line.
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>>
+where F: FnMut(&str) -> Result<T>, {
+    input.lines().enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.offset_lines(i)))
+        .collect()
+}
+
+/// Parses `part` of `line` as a number, reporting where it sits in `line` when it is not one.
+pub fn parse_number<T: FromStr>(line: &str, part: &str) -> Result<T> {
+    part.parse::<T>().map_err(|_| Error::parse(line, part, format!("invalid number `{}`", part)))
+}
+
+#[test]
+fn locates_lines_in_joined_sections () {
+    let sections = vec!["a\nb\n".to_string(), "c\n\n".to_string(), "d".to_string()];
+
+    assert_eq!(join_sections(&sections), "a\nb\n\nc\n\nd");
+    assert_eq!(locate_section(&sections, 2), (0, 2));
+    assert_eq!(locate_section(&sections, 4), (1, 1));
+    assert_eq!(locate_section(&sections, 6), (2, 1));
+}
+
+#[test]
+fn numbers_parse_errors_by_line () {
+    let error = parse_lines("1\n2\nx3", |line| parse_number::<u32>(line, line)).unwrap_err();
+
+    match error {
+        Error::Parse(error) => assert_eq!((error.line, error.column, error.text.as_str()), (3, 1, "x3")),
+        error => panic!("unexpected error {}", error),
+    }
+}
@@ -0,0 +1,15 @@
+pub mod error;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod records;
+pub mod solution;
+pub mod viz;
+
+pub use error::{Error, ParseError, Result};
+pub use grid::{parse_cells, Cell, Grid};
+pub use image::{Palette, Rgb, RgbImage};
+pub use input::{join_sections, lines, locate_section, parse_lines, parse_number, read_input, read_lines, read_sections};
+pub use records::{records, Record, Records};
+pub use solution::{run_solution, Answer, Details, Solution, Solver, SolverFor};
+pub use viz::{Control, Frame, Layer, Player};
@@ -0,0 +1,103 @@
+use crate::error::Result;
+use std::io::{self, BufRead};
+
+/// A group of consecutive non-blank lines, with trailing whitespace (including `\r`) removed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// The line the record starts on, counting from 1.
+    pub line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Record {
+    /// Parses the line at `index` within the record, reporting errors at its line in the whole input.
+    pub fn parse_line<T, F>(&self, index: usize, f: F) -> Result<T>
+    where F: FnOnce(&str) -> Result<T> {
+        f(&self.lines[index]).map_err(|e| e.offset_lines(self.line - 1 + index))
+    }
+
+    /// Parses every line of the record, as `parse_line` does.
+    pub fn parse_lines<T, F>(&self, mut f: F) -> Result<Vec<T>>
+    where F: FnMut(&str) -> Result<T> {
+        (0..self.lines.len()).map(|index| self.parse_line(index, &mut f)).collect()
+    }
+}
+
+/// Splits a reader into records on blank lines, reading one record at a time.
+pub struct Records<R> {
+    lines: io::Lines<R>,
+    line: usize,
+}
+
+impl<R: BufRead> Iterator for Records<R> {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<io::Result<Record>> {
+        let mut record = Record { line: 0, lines: Vec::new() };
+
+        for line in &mut self.lines {
+            let line = match line {
+                Ok(line) => line,
+                Err(error) => return Some(Err(error)),
+            };
+            self.line += 1;
+
+            let line = line.trim_end();
+            if line.is_empty() {
+                if record.lines.is_empty() {
+                    continue;
+                }
+                break;
+            }
+
+            if record.lines.is_empty() {
+                record.line = self.line;
+            }
+            record.lines.push(line.to_string());
+        }
+
+        if record.lines.is_empty() {
+            None
+        } else {
+            Some(Ok(record))
+        }
+    }
+}
+
+pub fn records<R: BufRead>(reader: R) -> Records<R> {
+    Records { lines: reader.lines(), line: 0 }
+}
+
+#[cfg(test)]
+fn summarise(input: &str) -> Vec<(usize, String)> {
+    records(input.as_bytes())
+        .map(|record| record.unwrap())
+        .map(|record| (record.line, record.lines.join("|")))
+        .collect()
+}
+
+#[test]
+fn splits_on_blank_lines () {
+    assert_eq!(summarise("a\nb\n\nc"), vec![(1, "a|b".to_string()), (4, "c".to_string())]);
+}
+
+#[test]
+fn skips_repeated_blank_lines () {
+    assert_eq!(summarise("\na\n\n\nb\n"), vec![(2, "a".to_string()), (5, "b".to_string())]);
+}
+
+#[test]
+fn strips_carriage_returns_and_trailing_whitespace () {
+    assert_eq!(summarise("a \r\nb\r\n  \r\nc\t\r\n"), vec![(1, "a|b".to_string()), (4, "c".to_string())]);
+}
+
+#[test]
+fn reports_errors_at_their_line_in_the_input () {
+    let record = records("x\n\ny\nz 1".as_bytes()).nth(1).unwrap().unwrap();
+    let error = record.parse_lines(|line| crate::parse_number::<u32>(line, line)).unwrap_err();
+
+    match error {
+        crate::Error::Parse(error) => assert_eq!(error.line, 3),
+        error => panic!("unexpected error {}", error),
+    }
+}
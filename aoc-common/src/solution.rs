@@ -0,0 +1,160 @@
+use crate::error::Result;
+use crate::input::{join_sections, read_input};
+use crate::viz::Frame;
+use log::debug;
+use std::any::{type_name, Any};
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+use std::process;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+    List(Vec<Answer>),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::UInt(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::List(values) => {
+                let values : Vec<String> = values.iter().map(|x| x.to_string()).collect();
+                write!(f, "{}", values.join(","))
+            }
+            Answer::Unsolved => write!(f, "not solved"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(impl From<$source> for Answer {
+            fn from(value: $source) -> Answer {
+                Answer::$variant(value as $target)
+            }
+        })*
+    };
+}
+
+answer_from!(Int, i64, i32, i64);
+answer_from!(UInt, u64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(values: Vec<T>) -> Answer {
+        Answer::List(values.into_iter().map(|x| x.into()).collect())
+    }
+}
+
+/// Named facts behind an answer, such as which entries were picked.
+pub type Details = Vec<(&'static str, Answer)>;
+
+/// One day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Facts behind the answer to `part`, reported alongside it in machine-readable output.
+    fn details(_input: &Self::Input, _part: u8) -> Details {
+        Vec::new()
+    }
+
+    /// Snapshots of the state behind `part` as it evolves, for stepping through with `aoc viz`.
+    fn frames(_input: &Self::Input, _part: u8) -> Vec<Frame> {
+        Vec::new()
+    }
+}
+
+/// Object-safe view of a `Solution`, so days with different input types can share one registry.
+pub trait Solver: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn part1(&self, input: &dyn Any) -> Answer;
+    fn part2(&self, input: &dyn Any) -> Answer;
+    fn details(&self, input: &dyn Any, part: u8) -> Details;
+    fn frames(&self, input: &dyn Any, part: u8) -> Vec<Frame>;
+
+    fn solve(&self, input: &dyn Any, part: u8) -> Answer {
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => Answer::Unsolved,
+        }
+    }
+}
+
+pub struct SolverFor<S> {
+    solution: PhantomData<fn() -> S>,
+}
+
+impl<S> SolverFor<S> {
+    pub const fn new() -> SolverFor<S> {
+        SolverFor { solution: PhantomData }
+    }
+}
+
+impl<S> Default for SolverFor<S> {
+    fn default() -> SolverFor<S> {
+        SolverFor::new()
+    }
+}
+
+impl<S> Solver for SolverFor<S>
+where S: Solution, S::Input: 'static {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        let parsed = S::parse(input)?;
+        debug!("{}: parsed {} lines", type_name::<S>(), input.lines().count());
+        Ok(Box::new(parsed))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Answer {
+        let answer = S::part1(input.downcast_ref::<S::Input>().expect("Input parsed by another solution"));
+        debug!("{}: part 1 is {}", type_name::<S>(), answer);
+        answer
+    }
+
+    fn part2(&self, input: &dyn Any) -> Answer {
+        let answer = S::part2(input.downcast_ref::<S::Input>().expect("Input parsed by another solution"));
+        debug!("{}: part 2 is {}", type_name::<S>(), answer);
+        answer
+    }
+
+    fn details(&self, input: &dyn Any, part: u8) -> Details {
+        S::details(input.downcast_ref::<S::Input>().expect("Input parsed by another solution"), part)
+    }
+
+    fn frames(&self, input: &dyn Any, part: u8) -> Vec<Frame> {
+        S::frames(input.downcast_ref::<S::Input>().expect("Input parsed by another solution"), part)
+    }
+}
+
+fn solve_files<S: Solution>(files: &[&str]) -> Result<()> {
+    let sections = files.iter().map(read_input).collect::<io::Result<Vec<String>>>()?;
+    let parsed = S::parse(&join_sections(&sections)).map_err(|e| e.in_sections(files, &sections))?;
+
+    println!("{}", S::part1(&parsed));
+    println!("{}", S::part2(&parsed));
+    Ok(())
+}
+
+/// Solves the puzzle input read from `files` and prints both answers, one per line.
+/// Unreadable or malformed input is reported on stderr, exiting with a failure status.
+pub fn run_solution<S: Solution>(files: &[&str]) {
+    if let Err(error) = solve_files::<S>(files) {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
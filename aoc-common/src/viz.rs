@@ -0,0 +1,223 @@
+use crate::grid::{Cell, Grid};
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+/// One 2D slice of a frame. Flat grids have a single layer at z = w = 0; cubes have one per (z, w).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layer {
+    pub z: i32,
+    pub w: i32,
+    pub grid: Grid<char>,
+}
+
+/// A snapshot of a day's state, such as one generation of a cellular automaton.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub label: String,
+    pub layers: Vec<Layer>,
+}
+
+impl Frame {
+    /// A frame of a single flat grid.
+    pub fn from_grid<T: Cell, L: Into<String>>(label: L, grid: &Grid<T>) -> Frame {
+        Frame { label: label.into(), layers: vec![Layer { z: 0, w: 0, grid: grid.map(Cell::to_char) }] }
+    }
+
+    pub fn layer(&self, z: i32, w: i32) -> Option<&Layer> {
+        self.layers.iter().find(|layer| layer.z == z && layer.w == w)
+    }
+}
+
+/// The frame as plain text: its label, then each layer, headed by its (z, w) when there are several.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.label)?;
+        for layer in &self.layers {
+            if self.layers.len() > 1 {
+                writeln!(f, "\nz={}, w={}", layer.z, layer.w)?;
+            }
+            write!(f, "{}", layer.grid)?;
+        }
+        Ok(())
+    }
+}
+
+/// What the viewer asked the player to do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    Next,
+    Previous,
+    First,
+    Last,
+    TogglePlay,
+    Faster,
+    Slower,
+    /// Moves the selected layer by (dz, dw).
+    Layer(i32, i32),
+}
+
+const MIN_FPS: u32 = 1;
+const MAX_FPS: u32 = 60;
+
+/// Steps through frames, by hand or playing at a frame rate, showing one (z, w) layer at a time.
+pub struct Player {
+    frames: Vec<Frame>,
+    frame: usize,
+    playing: bool,
+    fps: u32,
+    z: i32,
+    w: i32,
+}
+
+impl Player {
+    pub fn new(frames: Vec<Frame>, fps: u32) -> Player {
+        Player { frames, frame: 0, playing: false, fps: fps.clamp(MIN_FPS, MAX_FPS), z: 0, w: 0 }
+    }
+
+    pub fn frame(&self) -> Option<&Frame> {
+        self.frames.get(self.frame)
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// How long to show each frame while playing.
+    pub fn delay(&self) -> Duration {
+        Duration::from_millis(1000 / self.fps as u64)
+    }
+
+    pub fn control(&mut self, control: Control) {
+        let last = self.frames.len().saturating_sub(1);
+
+        match control {
+            Control::Next => self.frame = (self.frame + 1).min(last),
+            Control::Previous => self.frame = self.frame.saturating_sub(1),
+            Control::First => self.frame = 0,
+            Control::Last => self.frame = last,
+            Control::TogglePlay => {
+                if self.frame == last {
+                    self.frame = 0;
+                }
+                self.playing = !self.playing;
+            }
+            Control::Faster => self.fps = (self.fps * 2).min(MAX_FPS),
+            Control::Slower => self.fps = (self.fps / 2).max(MIN_FPS),
+            Control::Layer(dz, dw) => {
+                self.z += dz;
+                self.w += dw;
+            }
+        }
+    }
+
+    /// Moves on a frame while playing, pausing on the last one.
+    pub fn tick(&mut self) {
+        if self.playing {
+            self.control(Control::Next);
+            self.playing = self.frame + 1 < self.frames.len();
+        }
+    }
+
+    /// The selected layer of the current frame and a status line, drawn from the top left of the
+    /// terminal. Live cells are bold and empty (`.`) cells dim.
+    pub fn render(&self) -> String {
+        let mut screen = String::from("\x1b[H\x1b[2J");
+
+        let frame = match self.frame() {
+            Some(frame) => frame,
+            None => return screen + "Nothing to show\r\n",
+        };
+        let _ = write!(screen, "{}\r\n\r\n", frame.label);
+
+        match frame.layer(self.z, self.w) {
+            Some(layer) => {
+                for row in layer.grid.rows() {
+                    for c in row {
+                        match c {
+                            '.' => screen.push_str("\x1b[2m.\x1b[0m"),
+                            c => { let _ = write!(screen, "\x1b[1m{}\x1b[0m", c); }
+                        }
+                    }
+                    screen.push_str("\r\n");
+                }
+            }
+            None => screen.push_str("(empty layer)\r\n"),
+        }
+
+        let _ = write!(screen, "\r\nframe {}/{}  {}  {} fps", self.frame + 1, self.frames.len(),
+                       if self.playing { "playing" } else { "paused" }, self.fps);
+        if frame.layers.len() > 1 {
+            let _ = write!(screen, "  z={} w={}", self.z, self.w);
+        }
+        screen.push_str("\r\n");
+        screen
+    }
+}
+
+#[cfg(test)]
+fn test_frames() -> Vec<Frame> {
+    (0..3).map(|i| {
+        let layers = (-i..=i).map(|z| Layer { z, w: 0, grid: Grid::from_fn(2, 1, |x, _| if x as i32 == i % 2 { '#' } else { '.' }) }).collect();
+        Frame { label: format!("Generation {}", i), layers }
+    }).collect()
+}
+
+#[test]
+fn steps_through_frames () {
+    let mut player = Player::new(test_frames(), 4);
+
+    player.control(Control::Previous);
+    assert_eq!(player.frame().unwrap().label, "Generation 0");
+    player.control(Control::Last);
+    player.control(Control::Next);
+    assert_eq!(player.frame().unwrap().label, "Generation 2");
+    player.control(Control::First);
+    assert_eq!(player.frame().unwrap().label, "Generation 0");
+}
+
+#[test]
+fn plays_until_the_last_frame () {
+    let mut player = Player::new(test_frames(), 4);
+
+    player.control(Control::TogglePlay);
+    player.tick();
+    assert!(player.is_playing());
+    player.tick();
+    assert!(!player.is_playing());
+    assert_eq!(player.frame().unwrap().label, "Generation 2");
+
+    player.control(Control::TogglePlay);
+    assert_eq!(player.frame().unwrap().label, "Generation 0");
+}
+
+#[test]
+fn changes_speed_within_limits () {
+    let mut player = Player::new(test_frames(), 4);
+
+    player.control(Control::Faster);
+    assert_eq!(player.delay(), Duration::from_millis(125));
+    for _ in 0..10 {
+        player.control(Control::Slower);
+    }
+    assert_eq!(player.delay(), Duration::from_secs(1));
+}
+
+#[test]
+fn renders_the_selected_layer () {
+    let mut player = Player::new(test_frames(), 4);
+    player.control(Control::Next);
+
+    assert!(player.render().contains("\x1b[2m.\x1b[0m\x1b[1m#\x1b[0m"));
+    assert!(player.render().contains("frame 2/3  paused  4 fps  z=0 w=0"));
+
+    player.control(Control::Layer(-2, 0));
+    assert!(player.render().contains("(empty layer)"));
+}
+
+#[test]
+fn prints_frames_as_plain_text () {
+    let frames = test_frames();
+
+    assert_eq!(frames[0].to_string(), "Generation 0\n#.\n");
+    assert_eq!(frames[1].to_string(), "Generation 1\n\nz=-1, w=0\n.#\n\nz=0, w=0\n.#\n\nz=1, w=0\n.#\n");
+}
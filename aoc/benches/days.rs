@@ -0,0 +1,25 @@
+use aoc::bench::bench_input;
+use aoc::days::DAYS;
+use aoc::golden::read_manifest;
+use aoc::inputs::DEFAULT_PROFILE;
+use aoc_common::read_input;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_days(c: &mut Criterion) {
+    let manifest = read_manifest().unwrap();
+
+    for day in DAYS.iter() {
+        let input = read_input(bench_input(day, &manifest, DEFAULT_PROFILE).path(day.day)).unwrap();
+        let parsed = day.solver.parse(&input).unwrap();
+
+        let mut group = c.benchmark_group(format!("day{}", day.day));
+        group.sample_size(10);
+        group.bench_function("parse", |b| b.iter(|| day.solver.parse(&input)));
+        group.bench_function("part1", |b| b.iter(|| day.solver.part1(parsed.as_ref())));
+        group.bench_function("part2", |b| b.iter(|| day.solver.part2(parsed.as_ref())));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+use crate::days::Day;
+use crate::golden::Expected;
+use crate::inputs::Source;
+use aoc_common::{read_input, Result};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How long each phase of a day's solution took.
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// The input a day is benchmarked on for `profile`: its puzzle input when `answers.txt` pins
+/// answers for it, or else the first example that it pins answers for, so answers can be checked.
+/// Days without answers use the puzzle input.
+pub fn bench_input(day: &Day, manifest: &[Expected], profile: &str) -> Source {
+    let profile = Source::Puzzle(profile.to_string());
+    let mut pinned = manifest.iter().filter(|x| x.day == day.day).map(|x| &x.source);
+
+    if pinned.clone().any(|source| *source == profile) {
+        return profile;
+    }
+    pinned.find(|source| matches!(source, Source::Example(_))).cloned().unwrap_or(profile)
+}
+
+fn median<F: FnMut()>(runs: usize, mut f: F) -> Duration {
+    let mut times : Vec<Duration> = (0..runs.max(1)).map(|_| {
+        let start = Instant::now();
+        f();
+        start.elapsed()
+    }).collect();
+
+    times.sort();
+    times[times.len() / 2]
+}
+
+/// Times parsing the input at `path` and solving both parts, keeping the median of `runs` runs of each.
+pub fn time_day(day: &Day, path: &Path, runs: usize) -> Result<Timings> {
+    let input = read_input(path)?;
+
+    let parsed = day.solver.parse(&input).map_err(|e| e.in_file(path))?;
+
+    Ok(Timings {
+        parse: median(runs, || { day.solver.parse(&input).ok(); }),
+        part1: median(runs, || { day.solver.part1(parsed.as_ref()); }),
+        part2: median(runs, || { day.solver.part2(parsed.as_ref()); }),
+    })
+}
+
+/// Formats a duration in milliseconds, for the summary table.
+pub fn format_ms(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[test]
+fn benches_the_puzzle_input_before_examples () {
+    let day = crate::days::get_day(20).unwrap();
+    let entry = |source: Source| Expected { day: 20, part: 1, source, answer: "0".to_string() };
+    let puzzle = Source::Puzzle("default".to_string());
+    let example = Source::Example("tiles".to_string());
+
+    assert_eq!(bench_input(day, &[entry(example.clone()), entry(puzzle.clone())], "default"), puzzle);
+    assert_eq!(bench_input(day, &[entry(example.clone())], "default"), example);
+    assert_eq!(bench_input(day, &[], "alice"), Source::Puzzle("alice".to_string()));
+}
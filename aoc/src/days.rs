@@ -0,0 +1,44 @@
+use aoc_common::{read_input, Result, Solver, SolverFor};
+use std::any::Any;
+use std::path::Path;
+
+pub struct Day {
+    pub day: u8,
+    pub solver: &'static dyn Solver,
+}
+
+impl Day {
+    /// Reads the puzzle input at `path` and parses it, naming the file and line of any parse error.
+    pub fn parse_file(&self, path: &Path) -> Result<Box<dyn Any>> {
+        let input = read_input(path)?;
+
+        self.solver.parse(&input).map_err(|e| e.in_file(path))
+    }
+}
+
+pub static DAYS: [Day; 20] = [
+    Day { day: 1,  solver: &SolverFor::<day1::Day1>::new() },
+    Day { day: 2,  solver: &SolverFor::<day2::Day2>::new() },
+    Day { day: 3,  solver: &SolverFor::<day3::Day3>::new() },
+    Day { day: 4,  solver: &SolverFor::<day4::Day4>::new() },
+    Day { day: 5,  solver: &SolverFor::<day5::Day5>::new() },
+    Day { day: 6,  solver: &SolverFor::<day6::Day6>::new() },
+    Day { day: 7,  solver: &SolverFor::<day7::Day7>::new() },
+    Day { day: 8,  solver: &SolverFor::<day8::Day8>::new() },
+    Day { day: 9,  solver: &SolverFor::<day9::Day9>::new() },
+    Day { day: 10, solver: &SolverFor::<day10::Day10>::new() },
+    Day { day: 11, solver: &SolverFor::<day11::Day11>::new() },
+    Day { day: 12, solver: &SolverFor::<day12::Day12>::new() },
+    Day { day: 13, solver: &SolverFor::<day13::Day13>::new() },
+    Day { day: 14, solver: &SolverFor::<day14::Day14>::new() },
+    Day { day: 15, solver: &SolverFor::<day15::Day15>::new() },
+    Day { day: 16, solver: &SolverFor::<day16::Day16>::new() },
+    Day { day: 17, solver: &SolverFor::<day17::Day17>::new() },
+    Day { day: 18, solver: &SolverFor::<day18::Day18>::new() },
+    Day { day: 19, solver: &SolverFor::<day19::Day19>::new() },
+    Day { day: 20, solver: &SolverFor::<day20::Day20>::new() },
+];
+
+pub fn get_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|x| x.day == day)
+}
@@ -0,0 +1,72 @@
+use aoc_common::{Frame, Palette, RgbImage};
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+fn save(image: &RgbImage, path: &Path, format: ImageFormat) -> io::Result<()> {
+    let out = BufWriter::new(File::create(path)?);
+
+    match format {
+        ImageFormat::Ppm => image.write_ppm(out),
+        #[cfg(feature = "png")]
+        ImageFormat::Png => image.write_png(out),
+        #[cfg(not(feature = "png"))]
+        ImageFormat::Png => Err(io::Error::other("PNG export needs aoc built with `--features png`")),
+    }
+}
+
+/// Writes every frame to `dir` as `frame-NNN`, with `-zZ-wW` after it for each layer of frames
+/// that have several, and returns the paths written.
+pub fn export_frames(frames: &[Frame], dir: &Path, format: ImageFormat, palette: &Palette, scale: usize) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut paths = Vec::new();
+
+    for (i, frame) in frames.iter().enumerate() {
+        for layer in &frame.layers {
+            let name = if frame.layers.len() > 1 {
+                format!("frame-{:03}-z{}-w{}.{}", i, layer.z, layer.w, format.extension())
+            } else {
+                format!("frame-{:03}.{}", i, format.extension())
+            };
+
+            let path = dir.join(name);
+            save(&RgbImage::from_grid(&layer.grid, palette, scale), &path, format)?;
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+#[test]
+fn names_a_file_per_frame_and_layer () {
+    use aoc_common::{Grid, Layer};
+
+    let layer = |z| Layer { z, w: 0, grid: Grid::from_fn(2, 2, |_, _| '#') };
+    let frames = vec![
+        Frame { label: "flat".to_string(), layers: vec![layer(0)] },
+        Frame { label: "cube".to_string(), layers: vec![layer(-1), layer(0)] },
+    ];
+    let dir = std::env::temp_dir().join(format!("aoc-export-{}", std::process::id()));
+
+    let paths = export_frames(&frames, &dir, ImageFormat::Ppm, &Palette::default(), 3).unwrap();
+    let names : Vec<_> = paths.iter().map(|path| path.file_name().unwrap().to_str().unwrap()).collect();
+
+    assert_eq!(names, vec!["frame-000.ppm", "frame-001-z-1-w0.ppm", "frame-001-z0-w0.ppm"]);
+    assert_eq!(fs::read(&paths[0]).unwrap().len(), "P6\n6 6\n255\n".len() + 6 * 6 * 3);
+    fs::remove_dir_all(dir).unwrap();
+}
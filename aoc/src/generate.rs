@@ -0,0 +1,787 @@
+//! Seeded generators for synthetic puzzle inputs of any size.
+//!
+//! The same day, size and seed always produce the same input. Where the way an input is built
+//! decides its answers, they are returned alongside it.
+
+use aoc_common::Grid;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{HashMap, HashSet};
+
+type Random = ChaCha8Rng;
+
+/// A generated puzzle input, with the answer to each part where it is known by construction.
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl Generated {
+    fn unsolved(lines: Vec<String>) -> Generated {
+        Generated { input: join(lines), answers: [None, None] }
+    }
+
+    fn solved<A: ToString, B: ToString>(lines: Vec<String>, part1: Option<A>, part2: Option<B>) -> Generated {
+        Generated {
+            input: join(lines),
+            answers: [part1.map(|x| x.to_string()), part2.map(|x| x.to_string())],
+        }
+    }
+}
+
+fn join(lines: Vec<String>) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Generates an input for `day` of roughly `size` lines, records or tiles, or `None` for a day
+/// with no generator. Days whose solvers cannot cope with any size clamp it; see each generator.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<Generated> {
+    let generator : fn(&mut Random, usize) -> Generated = match day {
+        1 => day1,
+        2 => day2,
+        3 => day3,
+        4 => day4,
+        5 => day5,
+        6 => day6,
+        7 => day7,
+        8 => day8,
+        9 => day9,
+        10 => day10,
+        11 => day11,
+        12 => day12,
+        13 => day13,
+        14 => day14,
+        15 => day15,
+        16 => day16,
+        17 => day17,
+        18 => day18,
+        19 => day19,
+        20 => day20,
+        _ => return None,
+    };
+
+    Some(generator(&mut Random::seed_from_u64(seed), size))
+}
+
+/// Expense entries with exactly one pair and one triple summing to 2020. The rest are above
+/// 1010, so they can only pair up with planted entries, and values that would are left out.
+fn day1(rng: &mut Random, size: usize) -> Generated {
+    loop {
+        let a = rng.gen_range(1..1010);
+        let (c, d) = (rng.gen_range(1..674), rng.gen_range(1..674));
+        let planted = [a, 2020 - a, c, d, 2020 - c - d];
+
+        let distinct : HashSet<i32> = planted.iter().cloned().collect();
+        let pairs = planted.iter().flat_map(|x| planted.iter().map(move |y| (x, y)))
+            .filter(|(x, y)| x <= y && *x + *y == 2020)
+            .count();
+        let triples = planted.iter().flat_map(|x| planted.iter().map(move |y| (x, y)))
+            .flat_map(|(x, y)| planted.iter().map(move |z| (x, y, z)))
+            .filter(|(x, y, z)| x <= y && y <= z && *x + *y + *z == 2020)
+            .count();
+        if distinct.len() != planted.len() || pairs != 1 || triples != 1 {
+            continue;
+        }
+
+        let excluded : HashSet<i32> = planted.iter()
+            .flat_map(|x| planted.iter().map(move |y| 2020 - x - y).chain(Some(2020 - x)))
+            .collect();
+        let mut values = planted.to_vec();
+        while values.len() < size {
+            let value = rng.gen_range(1011..2020);
+            if !excluded.contains(&value) {
+                values.push(value);
+            }
+        }
+        values.shuffle(rng);
+
+        let lines = values.iter().map(|x| x.to_string()).collect();
+        return Generated::solved(lines, Some(a * (2020 - a)), Some(c * d * (2020 - c - d)));
+    }
+}
+
+/// Password policies drawn from a small alphabet so that both policies pass often.
+fn day2(rng: &mut Random, size: usize) -> Generated {
+    let (mut valid1, mut valid2) = (0, 0);
+
+    let lines = (0..size).map(|_| {
+        let letter = rng.gen_range(b'a'..=b'e') as char;
+        let min = rng.gen_range(1..=5);
+        let max = rng.gen_range(min + 1..=min + 10);
+        let password : Vec<char> = (0..rng.gen_range(max..=max + 5)).map(|_| rng.gen_range(b'a'..=b'e') as char).collect();
+
+        let count = password.iter().filter(|c| **c == letter).count();
+        if count >= min && count <= max {
+            valid1 += 1;
+        }
+        if (password[min - 1] == letter) != (password[max - 1] == letter) {
+            valid2 += 1;
+        }
+
+        format!("{}-{} {}: {}", min, max, letter, password.iter().collect::<String>())
+    }).collect();
+
+    Generated::solved(lines, Some(valid1), Some(valid2))
+}
+
+/// A forest 31 wide and `size` tall.
+fn day3(rng: &mut Random, size: usize) -> Generated {
+    let forest = Grid::from_fn(31, size, |_, _| rng.gen_bool(0.2));
+    let trees = |right: usize, down: usize| {
+        (0..size).step_by(down).enumerate().filter(|(i, y)| forest[(i * right % 31, *y)]).count()
+    };
+    let product = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter()
+        .try_fold(1usize, |product, (right, down)| product.checked_mul(trees(*right, *down)));
+
+    Generated { input: forest.to_string(), answers: [Some(trees(3, 1).to_string()), product.map(|x| x.to_string())] }
+}
+
+const PASSPORT_FIELDS : [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+fn passport_value(rng: &mut Random, field: &str, valid: bool) -> String {
+    match (field, valid) {
+        ("byr", true) => rng.gen_range(1920..=2002).to_string(),
+        ("byr", false) => rng.gen_range(1850..1920).to_string(),
+        ("iyr", true) => rng.gen_range(2010..=2020).to_string(),
+        ("iyr", false) => rng.gen_range(2021..2100).to_string(),
+        ("eyr", true) => rng.gen_range(2020..=2030).to_string(),
+        ("eyr", false) => rng.gen_range(1990..2020).to_string(),
+        ("hgt", true) if rng.gen_bool(0.5) => format!("{}cm", rng.gen_range(150..=193)),
+        ("hgt", true) => format!("{}in", rng.gen_range(59..=76)),
+        ("hgt", false) => rng.gen_range(150..=193).to_string(),
+        ("hcl", true) => format!("#{:06x}", rng.gen_range(0..0x1000000)),
+        ("hcl", false) => format!("{:06x}", rng.gen_range(0..0x1000000)),
+        ("ecl", true) => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].choose(rng).unwrap().to_string(),
+        ("ecl", false) => ["xry", "zzz", "lzr", "red"].choose(rng).unwrap().to_string(),
+        ("pid", true) => format!("{:09}", rng.gen_range(0..1_000_000_000)),
+        ("pid", false) => format!("{:08}", rng.gen_range(0..100_000_000)),
+        _ => rng.gen_range(100..350).to_string(),
+    }
+}
+
+/// Passports that are complete and valid, complete with one bad value, or missing a field.
+fn day4(rng: &mut Random, size: usize) -> Generated {
+    let (mut valid1, mut valid2) = (0, 0);
+    let mut lines = Vec::new();
+
+    for _ in 0..size {
+        let missing = if rng.gen_bool(0.25) { PASSPORT_FIELDS.choose(rng).cloned() } else { None };
+        let invalid = if rng.gen_bool(0.3) { PASSPORT_FIELDS.choose(rng).cloned() } else { None };
+
+        let mut fields : Vec<String> = PASSPORT_FIELDS.iter()
+            .filter(|field| Some(**field) != missing)
+            .map(|field| format!("{}:{}", field, passport_value(rng, field, Some(*field) != invalid)))
+            .collect();
+        if rng.gen_bool(0.5) {
+            fields.push(format!("cid:{}", passport_value(rng, "cid", true)));
+        }
+        fields.shuffle(rng);
+
+        if missing.is_none() {
+            valid1 += 1;
+            if invalid.is_none() {
+                valid2 += 1;
+            }
+        }
+
+        let mut rest = &fields[..];
+        while !rest.is_empty() {
+            let (line, tail) = rest.split_at(rng.gen_range(1..=rest.len().min(4)));
+            lines.push(line.join(" "));
+            rest = tail;
+        }
+        lines.push(String::new());
+    }
+    lines.pop();
+
+    Generated::solved(lines, Some(valid1), Some(valid2))
+}
+
+/// A run of consecutive seats with one missing from the middle; at most 1000 passes.
+fn day5(rng: &mut Random, size: usize) -> Generated {
+    let size = size.clamp(3, 1000);
+    let start = rng.gen_range(1..=1022 - size);
+    let mine = start + rng.gen_range(1..size - 1);
+
+    let mut ids : Vec<usize> = (start..start + size).filter(|id| *id != mine).collect();
+    ids.shuffle(rng);
+
+    let lines = ids.iter().map(|id| {
+        (0..10).rev().map(|bit| {
+            let set = id & (1 << bit) != 0;
+            match (bit >= 3, set) {
+                (true, true) => 'B',
+                (true, false) => 'F',
+                (false, true) => 'R',
+                (false, false) => 'L',
+            }
+        }).collect()
+    }).collect();
+
+    Generated::solved(lines, Some(start + size - 1), Some(mine))
+}
+
+/// Groups of one to five people, each answering a handful of questions.
+fn day6(rng: &mut Random, size: usize) -> Generated {
+    let letters : Vec<char> = ('a'..='z').collect();
+    let (mut anyone, mut everyone) = (0, 0);
+    let mut lines = Vec::new();
+
+    for _ in 0..size {
+        let people : Vec<HashSet<char>> = (0..rng.gen_range(1..=5))
+            .map(|_| {
+                let count = rng.gen_range(1..=10);
+                letters.choose_multiple(rng, count).cloned().collect()
+            })
+            .collect();
+
+        anyone += people.iter().flatten().collect::<HashSet<_>>().len();
+        everyone += letters.iter().filter(|c| people.iter().all(|answers| answers.contains(c))).count();
+
+        lines.extend(people.iter().map(|answers| {
+            let mut answers : Vec<&char> = answers.iter().collect();
+            answers.sort();
+            answers.into_iter().collect::<String>()
+        }));
+        lines.push(String::new());
+    }
+    lines.pop();
+
+    Generated::solved(lines, Some(anyone), Some(everyone))
+}
+
+const ADJECTIVES : [&str; 16] = ["light", "dark", "bright", "muted", "faded", "dotted", "vibrant", "posh",
+                                 "pale", "wavy", "drab", "dull", "clear", "mirrored", "plaid", "striped"];
+const COLOURS : [&str; 16] = ["red", "orange", "white", "yellow", "gold", "olive", "plum", "blue",
+                              "black", "green", "teal", "tan", "violet", "silver", "beige", "maroon"];
+
+/// A name for the `index`th bag. Names run out of adjective and colour pairs after 256, so
+/// later ones gain a suffix of letters; bag names cannot contain digits.
+fn bag_name(index: usize) -> String {
+    let pairs = ADJECTIVES.len() * COLOURS.len();
+    let mut suffix = String::new();
+    let mut rest = index / pairs;
+    while rest > 0 {
+        suffix.push((b'a' + (rest % 26) as u8) as char);
+        rest /= 26;
+    }
+
+    let pair = index % pairs;
+    format!("{} {}{}", ADJECTIVES[pair % ADJECTIVES.len()], COLOURS[pair / ADJECTIVES.len()], suffix)
+}
+
+/// A deep bag graph: each bag only holds bags defined after it, so the nesting goes `size` levels
+/// down. Totals grow quickly with depth, so large sizes overflow the solver's counts, and the
+/// second answer is left unknown once they do.
+fn day7(rng: &mut Random, size: usize) -> Generated {
+    let size = size.max(2);
+    let gold = rng.gen_range(size / 4..=size / 2);
+    let names : Vec<String> = (0..size)
+        .map(|i| if i == gold { "shiny gold".to_string() } else { bag_name(i) })
+        .collect();
+
+    let contents : Vec<Vec<(u32, usize)>> = (0..size).map(|i| {
+        let candidates : Vec<usize> = (i + 1..size.min(i + 9)).collect();
+        let count = rng.gen_range(0..=2.min(candidates.len()));
+        candidates.choose_multiple(rng, count).map(|child| (rng.gen_range(1..=3), *child)).collect::<Vec<_>>()
+    }).collect();
+
+    let mut lines : Vec<String> = contents.iter().enumerate().map(|(i, children)| {
+        let children : Vec<String> = children.iter()
+            .map(|(count, child)| format!("{} {} {}", count, names[*child], if *count == 1 { "bag" } else { "bags" }))
+            .collect();
+
+        if children.is_empty() {
+            format!("{} bags contain no other bags.", names[i])
+        } else {
+            format!("{} bags contain {}.", names[i], children.join(", "))
+        }
+    }).collect();
+    lines.shuffle(rng);
+
+    let (mut holds_gold, mut inside) = (vec![false; size], vec![Some(0u32); size]);
+    for i in (0..size).rev() {
+        holds_gold[i] = contents[i].iter().any(|(_, child)| *child == gold || holds_gold[*child]);
+        inside[i] = contents[i].iter().try_fold(0u32, |total, (count, child)| {
+            total.checked_add(count.checked_mul(inside[*child]?.checked_add(1)?)?)
+        });
+    }
+
+    Generated::solved(lines, Some(holds_gold.iter().filter(|x| **x).count()), inside[gold])
+}
+
+/// A program that jumps back to the start after `acc` and `nop` instructions, so swapping that
+/// jump is the only fix. Swapping any earlier `nop` jumps back into code that already ran.
+fn day8(rng: &mut Random, size: usize) -> Generated {
+    let size = size.max(2);
+    let jump = rng.gen_range(size / 2..size);
+    let (mut before, mut total) = (0, 0);
+
+    let lines = (0..size).map(|i| {
+        if i == jump {
+            format!("jmp {:+}", -(i as i32))
+        } else if rng.gen_bool(0.6) {
+            let value = rng.gen_range(-50..=50);
+            total += value;
+            if i < jump {
+                before += value;
+            }
+            format!("acc {:+}", value)
+        } else if i < jump {
+            format!("nop {:+}", -rng.gen_range(0..=i as i32))
+        } else {
+            format!("nop {:+}", rng.gen_range(-100..=100))
+        }
+    }).collect();
+
+    Generated::solved(lines, Some(before), Some(total))
+}
+
+/// The next XMAS number: a sum of two of the smaller numbers in the last 25, to keep growth slow.
+fn xmas_sum(rng: &mut Random, window: &[i64]) -> i64 {
+    let mut smallest = window.to_vec();
+    smallest.sort();
+    let picks : Vec<&i64> = smallest[..4].choose_multiple(rng, 2).collect();
+
+    picks[0] + picks[1]
+}
+
+/// XMAS numbers with one planted invalid number that is the sum of an earlier contiguous run.
+/// Numbers roughly double every 25, so at most 1000 are generated.
+fn day9(rng: &mut Random, size: usize) -> Generated {
+    let size = size.clamp(30, 1000);
+
+    loop {
+        let mut values : Vec<i64> = (1..=60).collect();
+        values.shuffle(rng);
+        values.truncate(25);
+
+        let invalid_at = rng.gen_range(size.max(54) / 2..size);
+        while values.len() < invalid_at {
+            let next = xmas_sum(rng, &values[values.len() - 25..]);
+            values.push(next);
+        }
+
+        let start = rng.gen_range(0..invalid_at - 2);
+        let end = rng.gen_range(start + 2..=invalid_at.min(start + 6));
+        let invalid : i64 = values[start..end].iter().sum();
+
+        let window = &values[invalid_at - 25..];
+        let is_sum = window.iter().enumerate().any(|(i, x)| window[i + 1..].iter().any(|y| x + y == invalid));
+
+        let mut prefix = vec![0];
+        prefix.extend(values.iter().scan(0, |total, x| { *total += x; Some(*total) }));
+        let sums : HashSet<i64> = prefix.iter().cloned().collect();
+        let earlier_run = prefix[..start].iter().any(|total| sums.contains(&(total + invalid)));
+
+        if is_sum || earlier_run {
+            continue;
+        }
+
+        values.push(invalid);
+        while values.len() < size {
+            let next = xmas_sum(rng, &values[values.len() - 25..]);
+            values.push(next);
+        }
+
+        let run = &values[start..end];
+        let weakness = run.iter().min().unwrap() + run.iter().max().unwrap();
+        let lines = values.iter().map(|x| x.to_string()).collect();
+        return Generated::solved(lines, Some(invalid), Some(weakness));
+    }
+}
+
+/// Adapters in runs of up to four 1-jolt steps between 3-jolt steps. A run of `n` 1-jolt steps
+/// can be crossed in 1, 1, 2, 4 or 7 ways; the count of arrangements is left unknown once it
+/// overflows.
+fn day10(rng: &mut Random, size: usize) -> Generated {
+    const WAYS : [i64; 5] = [1, 1, 2, 4, 7];
+
+    let mut adapters = Vec::new();
+    let (mut joltage, mut ones, mut threes) = (0, 0, 1);
+    let mut arrangements = Some(1i64);
+
+    while adapters.len() < size {
+        let run = rng.gen_range(0..=4.min(size - adapters.len()));
+        for _ in 0..run {
+            joltage += 1;
+            adapters.push(joltage);
+        }
+        ones += run;
+        arrangements = arrangements.and_then(|x| x.checked_mul(WAYS[run]));
+
+        if adapters.len() < size {
+            joltage += 3;
+            adapters.push(joltage);
+            threes += 1;
+        }
+    }
+    adapters.shuffle(rng);
+
+    let lines = adapters.iter().map(|x| x.to_string()).collect();
+    Generated::solved(lines, Some(ones * threes), arrangements)
+}
+
+/// A square seating area `size` wide.
+fn day11(rng: &mut Random, size: usize) -> Generated {
+    let lines = (0..size)
+        .map(|_| (0..size).map(|_| if rng.gen_bool(0.75) { 'L' } else { '.' }).collect())
+        .collect();
+
+    Generated::unsolved(lines)
+}
+
+/// Navigation instructions, turning only in quarter turns.
+fn day12(rng: &mut Random, size: usize) -> Generated {
+    let lines = (0..size).map(|_| {
+        match *['N', 'S', 'E', 'W', 'F', 'F', 'L', 'R'].choose(rng).unwrap() {
+            turn @ 'L' | turn @ 'R' => format!("{}{}", turn, [90, 180, 270].choose(rng).unwrap()),
+            action => format!("{}{}", action, rng.gen_range(1..=100)),
+        }
+    }).collect();
+
+    Generated::unsolved(lines)
+}
+
+/// Prime bus ids placed so that a chosen timestamp is the first where they leave one after
+/// another. The product of the ids must fit the solver's arithmetic, so at most nine buses run.
+fn day13(rng: &mut Random, size: usize) -> Generated {
+    const PRIMES : [u64; 21] = [11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+
+    let ids : Vec<u64> = PRIMES.choose_multiple(rng, size.clamp(2, 9)).cloned().collect();
+    let product : u64 = ids.iter().product();
+    let timestamp = ids[0] * rng.gen_range(1..product / ids[0]);
+
+    let mut slots : HashMap<u64, u64> = HashMap::new();
+    slots.insert(0, ids[0]);
+    for id in &ids[1..] {
+        let mut slot = (id - timestamp % id) % id;
+        while slots.contains_key(&slot) {
+            slot += id;
+        }
+        slots.insert(slot, *id);
+    }
+
+    let last = *slots.keys().max().unwrap();
+    let buses : Vec<String> = (0..=last).map(|slot| slots.get(&slot).map_or("x".to_string(), |id| id.to_string())).collect();
+
+    let earliest = rng.gen_range(1000..1_000_000);
+    let (wait, id) = ids_in_order(&slots).map(|id| (id - earliest % id, id)).min_by_key(|(wait, _)| *wait).unwrap();
+
+    Generated::solved(vec![earliest.to_string(), buses.join(",")], Some(wait * id), Some(timestamp))
+}
+
+fn ids_in_order(slots: &HashMap<u64, u64>) -> impl Iterator<Item=u64> {
+    let mut ordered : Vec<(u64, u64)> = slots.iter().map(|(slot, id)| (*slot, *id)).collect();
+    ordered.sort();
+    ordered.into_iter().map(|(_, id)| id)
+}
+
+/// Masks with up to nine floating bits, each followed by a few writes.
+fn day14(rng: &mut Random, size: usize) -> Generated {
+    let lines = (0..size).map(|i| {
+        if i == 0 || rng.gen_bool(0.2) {
+            let floating = rng.gen_range(0..=9);
+            let mut mask : Vec<char> = (0..36).map(|bit| if bit < floating { 'X' } else if rng.gen_bool(0.5) { '1' } else { '0' }).collect();
+            mask.shuffle(rng);
+            format!("mask = {}", mask.iter().collect::<String>())
+        } else {
+            format!("mem[{}] = {}", rng.gen_range(0..65536), rng.gen_range(0..1u64 << 36))
+        }
+    }).collect();
+
+    Generated::unsolved(lines)
+}
+
+/// `size` distinct starting numbers.
+fn day15(rng: &mut Random, size: usize) -> Generated {
+    let size = size.max(1);
+    let mut numbers : Vec<usize> = (0..size * 2).collect();
+    numbers.shuffle(rng);
+
+    let starting : Vec<String> = numbers[..size].iter().map(|x| x.to_string()).collect();
+    Generated::unsolved(vec![starting.join(",")])
+}
+
+const TICKET_FIELDS : [&str; 20] = ["departure location", "departure station", "departure platform", "departure track",
+                                    "departure date", "departure time", "arrival location", "arrival station",
+                                    "arrival platform", "arrival track", "class", "duration", "price", "route",
+                                    "row", "seat", "train", "type", "wagon", "zone"];
+
+/// Twenty fields, our ticket and `size` nearby tickets. Each field has a level, and a field accepts
+/// the values of every column at its level or above, so exactly one column is left for each field
+/// in turn. Invalid values are above every range. The second answer is only known when a nearby
+/// ticket is valid, since the solver deduces nothing from ours.
+fn day16(rng: &mut Random, size: usize) -> Generated {
+    let mut field_levels : Vec<usize> = (0..20).collect();
+    field_levels.shuffle(rng);
+    let mut column_levels : Vec<usize> = (0..20).collect();
+    column_levels.shuffle(rng);
+
+    let mut lines : Vec<String> = TICKET_FIELDS.iter().zip(&field_levels).map(|(name, &level)| {
+        let (low, high, next) = if level == 19 {
+            (1901, 1950, 1951)
+        } else {
+            let split = rng.gen_range(level..=18);
+            (level * 100 + 1, split * 100 + 99, split * 100 + 101)
+        };
+        format!("{}: {}-{} or {}-1999", name, low, high, next)
+    }).collect();
+
+    let valid_ticket = |rng: &mut Random| -> Vec<usize> {
+        column_levels.iter().map(|level| level * 100 + rng.gen_range(1..=99)).collect()
+    };
+
+    let my_ticket = valid_ticket(rng);
+    let departure_product = field_levels.iter().zip(TICKET_FIELDS.iter())
+        .filter(|(_, name)| name.starts_with("departure"))
+        .map(|(level, _)| my_ticket[column_levels.iter().position(|x| x == level).unwrap()] as u64)
+        .try_fold(1u64, |product, value| product.checked_mul(value));
+
+    lines.push(String::new());
+    lines.push("your ticket:".to_string());
+    lines.push(my_ticket.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","));
+    lines.push(String::new());
+    lines.push("nearby tickets:".to_string());
+
+    let (mut invalid_sum, mut any_valid) = (0, false);
+    for _ in 0..size {
+        let mut ticket = valid_ticket(rng);
+        if rng.gen_bool(0.25) {
+            let invalid = rng.gen_range(2000..3000);
+            invalid_sum += invalid;
+            ticket[rng.gen_range(0..20)] = invalid;
+        } else {
+            any_valid = true;
+        }
+        lines.push(ticket.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","));
+    }
+
+    Generated::solved(lines, Some(invalid_sum), departure_product.filter(|_| any_valid))
+}
+
+/// A square starting slice `size` wide.
+fn day17(rng: &mut Random, size: usize) -> Generated {
+    let slice = Grid::from_fn(size, size, |_, _| rng.gen_bool(0.4));
+
+    Generated { input: slice.to_string(), answers: [None, None] }
+}
+
+/// An expression with its values left to right, and with addition before multiplication.
+fn expression(rng: &mut Random, depth: u32) -> (String, Option<u64>, Option<u64>) {
+    let mut text = String::new();
+    let (mut left_to_right, mut product, mut sum) = (Some(0u64), Some(1u64), Some(0u64));
+
+    for term in 0..rng.gen_range(2..=5) {
+        let add = term == 0 || rng.gen_bool(0.5);
+        if term > 0 {
+            text.push_str(if add { " + " } else { " * " });
+            if !add {
+                product = product.and_then(|x| sum.and_then(|y| x.checked_mul(y)));
+                sum = Some(0);
+            }
+        }
+
+        let (value, value1, value2) = if depth < 2 && rng.gen_bool(0.25) {
+            let (inner, value1, value2) = expression(rng, depth + 1);
+            (format!("({})", inner), value1, value2)
+        } else {
+            let digit = rng.gen_range(1..=9);
+            (digit.to_string(), Some(digit), Some(digit))
+        };
+        text.push_str(&value);
+
+        left_to_right = match (left_to_right, value1) {
+            (Some(x), Some(y)) if add => x.checked_add(y),
+            (Some(x), Some(y)) => x.checked_mul(y),
+            _ => None,
+        };
+        sum = sum.and_then(|x| value2.and_then(|y| x.checked_add(y)));
+    }
+
+    (text, left_to_right, product.and_then(|x| sum.and_then(|y| x.checked_mul(y))))
+}
+
+/// Expressions nested at most three deep; any whose value passes 10^12 is drawn again.
+fn day18(rng: &mut Random, size: usize) -> Generated {
+    let (mut total1, mut total2) = (0, 0);
+    let mut lines = Vec::new();
+
+    while lines.len() < size {
+        if let (line, Some(value1), Some(value2)) = expression(rng, 0) {
+            if value1 <= 1_000_000_000_000 && value2 <= 1_000_000_000_000 {
+                total1 += value1;
+                total2 += value2;
+                lines.push(line);
+            }
+        }
+    }
+
+    Generated::solved(lines, Some(total1), Some(total2))
+}
+
+/// Builds rules that split every string of `length` letters between rule 42 and rule 31.
+struct RuleBuilder {
+    length: usize,
+    is_42: HashMap<String, bool>,
+    rules: Vec<String>,
+    next_id: u32,
+}
+
+impl RuleBuilder {
+    fn new_id(&mut self) -> u32 {
+        loop {
+            self.next_id += 1;
+            if ![8, 11, 31, 42].contains(&self.next_id) {
+                return self.next_id;
+            }
+        }
+    }
+
+    fn add(&mut self, id: u32, alternatives: &[Vec<u32>]) {
+        let alternatives : Vec<String> = alternatives.iter()
+            .map(|sequence| sequence.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(" "))
+            .collect();
+        self.rules.push(format!("{}: {}", id, alternatives.join(" | ")));
+    }
+
+    /// The alternatives matching the completions of `prefix` that belong to rule 42, or to 31.
+    /// Each starts with a letter, so matching never needs to backtrack.
+    fn alternatives(&mut self, prefix: &str, is_42: bool) -> Vec<Vec<u32>> {
+        let mut alternatives = Vec::new();
+
+        for (letter, id) in [('a', 1), ('b', 2)] {
+            let next = format!("{}{}", prefix, letter);
+            if next.len() == self.length {
+                if self.is_42[&next] == is_42 {
+                    alternatives.push(vec![id]);
+                }
+            } else {
+                let rest = self.alternatives(&next, is_42);
+                if !rest.is_empty() {
+                    let rest_id = self.new_id();
+                    self.add(rest_id, &rest);
+                    alternatives.push(vec![id, rest_id]);
+                }
+            }
+        }
+        alternatives
+    }
+}
+
+/// Rules where 42 and 31 each match some of the six letter strings, and messages made of those
+/// strings. Each message's answer follows from splitting it into six letter chunks.
+fn day19(rng: &mut Random, size: usize) -> Generated {
+    const LENGTH : usize = 6;
+
+    let strings : Vec<String> = (0..1 << LENGTH)
+        .map(|bits: u32| (0..LENGTH).map(|i| if bits & (1 << i) == 0 { 'a' } else { 'b' }).collect())
+        .collect();
+    let mut is_42 : HashMap<String, bool> = strings.iter().map(|x| (x.clone(), rng.gen_bool(0.5))).collect();
+    is_42.insert(strings[0].clone(), true);
+    is_42.insert(strings[1].clone(), false);
+
+    let mut builder = RuleBuilder { length: LENGTH, is_42, rules: Vec::new(), next_id: 2 };
+    for (id, is_42) in [(42, true), (31, false)] {
+        let alternatives = builder.alternatives("", is_42);
+        builder.add(id, &alternatives);
+    }
+
+    let RuleBuilder { rules: mut lines, is_42, .. } = builder;
+    lines.extend(vec!["0: 8 11".to_string(), "8: 42".to_string(), "11: 42 31".to_string(),
+                      "1: \"a\"".to_string(), "2: \"b\"".to_string()]);
+    lines.shuffle(rng);
+    lines.push(String::new());
+
+    let (group_42, group_31) : (Vec<&String>, Vec<&String>) = strings.iter().partition(|x| is_42[*x]);
+    let (mut valid1, mut valid2) = (0, 0);
+
+    for _ in 0..size {
+        let (count_42, count_31) = match rng.gen_range(0..4) {
+            0 => (2, 1),
+            1 => { let b = rng.gen_range(1..=3); (rng.gen_range(b + 1..=b + 3), b) }
+            _ => (rng.gen_range(0..=4), rng.gen_range(0..=3)),
+        };
+        let mut chunks : Vec<bool> = (0..count_42).map(|_| true).chain((0..count_31).map(|_| false)).collect();
+        if rng.gen_bool(0.2) {
+            chunks.shuffle(rng);
+        }
+        let mut message : String = chunks.iter()
+            .map(|is_42| if *is_42 { group_42.choose(rng) } else { group_31.choose(rng) }.unwrap().as_str())
+            .collect();
+        if message.is_empty() || rng.gen_bool(0.1) {
+            message.push('a');
+        }
+
+        let chunks : Vec<bool> = message.as_bytes().chunks(LENGTH).map(|x| is_42.get(std::str::from_utf8(x).unwrap()) == Some(&true)).collect();
+        let whole = message.len().is_multiple_of(LENGTH);
+        let leading = chunks.iter().take_while(|x| **x).count();
+        let trailing = chunks.len() - leading;
+
+        if whole && chunks == [true, true, false] {
+            valid1 += 1;
+        }
+        if whole && chunks[leading..].iter().all(|x| !*x) && trailing >= 1 && leading > trailing {
+            valid2 += 1;
+        }
+        lines.push(message);
+    }
+
+    Generated::solved(lines, Some(valid1), Some(valid2))
+}
+
+/// Packs a run of pixels into bits, for comparing tile edges.
+fn edge_bits<'a, I: Iterator<Item=&'a bool>>(pixels: I) -> u32 {
+    pixels.fold(0, |bits, pixel| bits << 1 | *pixel as u32)
+}
+
+/// Tiles cut from one random image, rotated, flipped and shuffled. Every edge pattern is made
+/// unique, even read backwards, so each tile fits in only one place. There are only 496 such
+/// patterns of 10 pixels, so the map is capped at 15 tiles square, and the pixels at the tiles'
+/// corners alternate by row so that the edges share them out evenly enough to all fit.
+fn day20(rng: &mut Random, size: usize) -> Generated {
+    let side = ((size as f64).sqrt() as usize).clamp(2, 15);
+    let width = 9 * side + 1;
+    let mut image = Grid::from_fn(width, width, |x, y| {
+        if x % 9 == 0 && y % 9 == 0 { y / 9 % 2 == 1 } else { rng.gen_bool(0.5) }
+    });
+
+    let mut seen = HashSet::new();
+    for horizontal in [true, false] {
+        for (line, segment) in (0..=side).flat_map(|line| (0..side).map(move |segment| (line, segment))) {
+            let position = |i: usize| if horizontal { (9 * segment + i, 9 * line) } else { (9 * line, 9 * segment + i) };
+
+            loop {
+                let bits = edge_bits((0..10).map(|i| &image[position(i)]));
+                let reversed = bits.reverse_bits() >> 22;
+                if bits != reversed && seen.insert(bits.min(reversed)) {
+                    break;
+                }
+                for i in 1..9 {
+                    image[position(i)] = rng.gen_bool(0.5);
+                }
+            }
+        }
+    }
+
+    let mut ids : Vec<u32> = (1000..10000).collect();
+    ids.shuffle(rng);
+    let mut tiles : Vec<(u32, Grid<bool>)> = (0..side * side).map(|i| {
+        let (x, y) = (i % side * 9, i / side * 9);
+        let mut tile = Grid::from_fn(10, 10, |dx, dy| image[(x + dx, y + dy)]);
+        for _ in 0..rng.gen_range(0..4) {
+            tile = tile.rotate_cw();
+        }
+        if rng.gen_bool(0.5) {
+            tile = tile.flip_horizontal();
+        }
+        (ids[i], tile)
+    }).collect();
+
+    let corners : u64 = [0, side - 1, side * (side - 1), side * side - 1].iter().map(|i| tiles[*i].0 as u64).product();
+    tiles.shuffle(rng);
+
+    let input = tiles.iter().map(|(id, tile)| format!("Tile {}:\n{}", id, tile)).collect::<Vec<_>>().join("\n");
+    Generated { input, answers: [Some(corners.to_string()), None] }
+}
@@ -0,0 +1,82 @@
+use crate::days;
+use crate::inputs::{inputs_root, Source};
+use aoc_common::{parse_lines, parse_number, read_input, Answer, Error, Result};
+use std::path::PathBuf;
+
+/// One known-good answer from the answers manifest.
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    pub source: Source,
+    pub answer: String,
+}
+
+impl Expected {
+    /// Solves this entry's puzzle input again with the current solver.
+    pub fn solve(&self) -> Result<Answer> {
+        let day = days::get_day(self.day).expect("manifest days are checked when it is read");
+
+        let parsed = day.parse_file(&self.source.path(self.day))?;
+        Ok(day.solver.solve(parsed.as_ref(), self.part))
+    }
+}
+
+/// Where the answers manifest lives: `answers.txt` in the inputs root, beside the inputs it covers.
+pub fn manifest_path() -> PathBuf {
+    inputs_root().join("answers.txt")
+}
+
+/// Reads every entry of the answers manifest.
+pub fn read_manifest() -> Result<Vec<Expected>> {
+    let path = manifest_path();
+
+    parse_manifest(&read_input(&path)?).map_err(|e| e.in_file(&path))
+}
+
+fn parse_manifest(text: &str) -> Result<Vec<Expected>> {
+    Ok(parse_lines(text, parse_entry)?.into_iter().flatten().collect())
+}
+
+/// Parses a `<day> <part> <input> <answer>` line, where the input is a profile or `example:<name>`,
+/// and blank lines and `#` comments hold no entry.
+fn parse_entry(line: &str) -> Result<Option<Expected>> {
+    let content = line.split('#').next().unwrap().trim_end();
+    let mut fields = content.split_whitespace();
+
+    let day_field = match fields.next() {
+        Some(field) => field,
+        None => return Ok(None),
+    };
+    let missing = || Error::parse(line, &content[content.len()..], "expected `<day> <part> <input> <answer>`");
+
+    let day = parse_number(line, day_field)?;
+    if days::get_day(day).is_none() {
+        return Err(Error::parse(line, day_field, format!("Day {} has no solution", day)));
+    }
+
+    let part_field = fields.next().ok_or_else(missing)?;
+    let part = parse_number(line, part_field)?;
+    if part != 1 && part != 2 {
+        return Err(Error::parse(line, part_field, "parts are 1 or 2"));
+    }
+
+    let source = Source::new(fields.next().ok_or_else(missing)?);
+
+    let answer_field = fields.next().ok_or_else(missing)?;
+    let answer_start = answer_field.as_ptr() as usize - content.as_ptr() as usize;
+
+    Ok(Some(Expected { day, part, source, answer: content[answer_start..].to_string() }))
+}
+
+#[test]
+fn parses_entries_and_skips_comments () {
+    let manifest = parse_manifest("# day part input answer\n\n16 2 alice 42 # pinned\n20 2 example:tiles not solved\n").unwrap();
+
+    assert_eq!(manifest.len(), 2);
+    assert_eq!((manifest[0].day, manifest[0].part, manifest[0].answer.as_str()), (16, 2, "42"));
+    assert_eq!(manifest[0].source, Source::Puzzle("alice".to_string()));
+    assert_eq!(manifest[1].source, Source::Example("tiles".to_string()));
+    assert_eq!(manifest[1].answer, "not solved");
+    assert!(parse_manifest("3 1 default").is_err());
+    assert!(parse_manifest("3 4 default 7").is_err());
+}
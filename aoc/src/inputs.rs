@@ -0,0 +1,77 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The profile puzzle inputs are read from when none is named.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Where puzzle inputs live: `$AOC_INPUTS` if it is set, otherwise `inputs` at the workspace root.
+///
+/// Under it, `<profile>/dayNN.txt` holds each account's puzzle input for a day, and
+/// `examples/dayNN-<name>.txt` the examples shared by every profile.
+pub fn inputs_root() -> PathBuf {
+    match env::var_os("AOC_INPUTS") {
+        Some(root) => PathBuf::from(root),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("inputs"),
+    }
+}
+
+/// One of the inputs kept for a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// The puzzle input of the account with this profile.
+    Puzzle(String),
+    /// A named example.
+    Example(String),
+}
+
+impl Source {
+    /// Reads `example:<name>` as an example, and anything else as a profile.
+    pub fn new(name: &str) -> Source {
+        match name.strip_prefix("example:") {
+            Some(example) => Source::Example(example.to_string()),
+            None => Source::Puzzle(name.to_string()),
+        }
+    }
+
+    /// Where this input of `day` lives under the inputs root.
+    pub fn path(&self, day: u8) -> PathBuf {
+        match self {
+            Source::Puzzle(profile) => inputs_root().join(profile).join(format!("day{:02}.txt", day)),
+            Source::Example(name) => inputs_root().join("examples").join(format!("day{:02}-{}.txt", day, name)),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Puzzle(profile) => write!(f, "{}", profile),
+            Source::Example(name) => write!(f, "example:{}", name),
+        }
+    }
+}
+
+/// The names of the examples kept for `day`, sorted.
+pub fn examples(day: u8) -> Vec<String> {
+    let prefix = format!("day{:02}-", day);
+    let mut names : Vec<String> = fs::read_dir(inputs_root().join("examples")).into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|file| Some(file.strip_prefix(&prefix)?.strip_suffix(".txt")?.to_string()))
+        .collect();
+
+    names.sort();
+    names
+}
+
+#[test]
+fn lays_out_profiles_and_examples () {
+    let root = inputs_root();
+
+    assert_eq!(Source::new("default").path(3), root.join("default").join("day03.txt"));
+    assert_eq!(Source::new("example:glider").path(17), root.join("examples").join("day17-glider.txt"));
+    assert_eq!(Source::new("example:glider").to_string(), "example:glider");
+    assert_eq!(examples(17), vec!["glider"]);
+}
@@ -0,0 +1,9 @@
+pub mod bench;
+pub mod days;
+pub mod export;
+pub mod generate;
+pub mod golden;
+pub mod inputs;
+pub mod report;
+pub mod runner;
+pub mod viz;
@@ -0,0 +1,536 @@
+use aoc::bench::{bench_input, format_ms, time_day};
+use aoc::days;
+use aoc::export::{export_frames, ImageFormat};
+use aoc::generate::generate;
+use aoc::golden::read_manifest;
+use aoc::inputs::{examples, Source, DEFAULT_PROFILE};
+use aoc::report::part_json;
+use aoc::runner::run_all;
+use aoc::viz;
+use aoc_common::{read_input, Palette};
+use clap::{Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
+use std::any::Any;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Instant;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2020 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log what the solvers do to stderr: -v for progress, -vv for every step. RUST_LOG overrides it
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum AuditFormat {
+    Table,
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day's puzzle, or every day at once
+    Run {
+        /// Puzzle day, 1-20
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Solve every day concurrently and check the answers against answers.txt
+        #[arg(long, conflicts_with_all = ["day", "input", "example"])]
+        all: bool,
+
+        /// With --all, solve on this many threads instead of one per core
+        #[arg(long, value_name = "N", requires = "all")]
+        jobs: Option<usize>,
+
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from PATH, or from stdin when PATH is `-`
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<PathBuf>,
+
+        /// Read the puzzle input of this profile, from `inputs/PROFILE/dayNN.txt`
+        #[arg(long, default_value = DEFAULT_PROFILE)]
+        profile: String,
+
+        /// Solve the example `inputs/examples/dayNN-NAME.txt` instead of the puzzle input
+        #[arg(long, value_name = "NAME")]
+        example: Option<String>,
+
+        /// Print answers as text, or as one JSON object per part
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+
+    /// Time parsing and both parts of every day, or of one day
+    Bench {
+        /// Puzzle day, 1-20
+        day: Option<u8>,
+
+        /// Time each phase this many times and report the median
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+
+        /// Time the puzzle inputs of this profile
+        #[arg(long, default_value = DEFAULT_PROFILE)]
+        profile: String,
+    },
+
+    /// Write a synthetic puzzle input to stdout, and any answers known for it to stderr
+    Gen {
+        /// Puzzle day, 1-20
+        day: u8,
+
+        /// How big to make the input: lines, records or tiles, depending on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Seed for the generator; the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+
+    /// Step through how a day's state evolves, such as day11's seating or day17's cubes
+    Viz {
+        /// Puzzle day, 1-20
+        day: u8,
+
+        /// Show the state behind this part
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Read the puzzle input from PATH, or from stdin when PATH is `-`
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<PathBuf>,
+
+        /// Read the puzzle input of this profile, from `inputs/PROFILE/dayNN.txt`
+        #[arg(long, default_value = DEFAULT_PROFILE)]
+        profile: String,
+
+        /// Show the example `inputs/examples/dayNN-NAME.txt` instead of the puzzle input
+        #[arg(long, value_name = "NAME")]
+        example: Option<String>,
+
+        /// Frames per second while playing
+        #[arg(long, default_value_t = 4)]
+        fps: u32,
+
+        /// Write every frame to DIR as an image instead of playing them
+        #[arg(long, value_name = "DIR")]
+        export_frames: Option<PathBuf>,
+
+        /// Image format of exported frames; PNG needs the `png` feature
+        #[arg(long, value_enum, default_value_t = ImageFormat::Ppm, requires = "export_frames")]
+        image_format: ImageFormat,
+
+        /// Draw each cell of exported frames as a square this many pixels wide
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64), requires = "export_frames")]
+        scale: u32,
+
+        /// Colours for exported frames, as `<char>=<rrggbb>` entries separated by commas
+        #[arg(long, value_name = "COLOURS", requires = "export_frames")]
+        palette: Option<String>,
+    },
+
+    /// Check every one of day2's passwords against a set of policies and say why each one fails
+    Audit {
+        /// Policy file with one policy per line; defaults to both of the puzzle's policies
+        #[arg(long, value_name = "PATH")]
+        policies: Option<PathBuf>,
+
+        /// Read the passwords from PATH, or from stdin when PATH is `-`
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<PathBuf>,
+
+        /// Read the passwords of this profile, from `inputs/PROFILE/day02.txt`
+        #[arg(long, default_value = DEFAULT_PROFILE)]
+        profile: String,
+
+        /// Read the example `inputs/examples/day02-NAME.txt` instead of the puzzle input
+        #[arg(long, value_name = "NAME")]
+        example: Option<String>,
+
+        /// Print a table, or CSV with one row per line and broken policy
+        #[arg(long, value_enum, default_value_t = AuditFormat::Table)]
+        format: AuditFormat,
+    },
+
+    /// Find the fewest of day1's expense report entries that sum to a total
+    Subset {
+        /// The total the entries have to sum to
+        #[arg(long, default_value_t = 2020, allow_hyphen_values = true)]
+        target: i64,
+
+        /// Only report a subset of at most this many entries
+        #[arg(long, value_name = "N")]
+        max_size: Option<usize>,
+
+        /// Read the entries from PATH, or from stdin when PATH is `-`
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<PathBuf>,
+
+        /// Read the entries of this profile, from `inputs/PROFILE/day01.txt`
+        #[arg(long, default_value = DEFAULT_PROFILE)]
+        profile: String,
+
+        /// Read the example `inputs/examples/day01-NAME.txt` instead of the puzzle input
+        #[arg(long, value_name = "NAME")]
+        example: Option<String>,
+    },
+
+    /// Count the trees day3's toboggan hits on any slopes, or find the best and worst slopes
+    Slopes {
+        /// Slope to try, as `<right>,<down>`, going left when RIGHT is negative, and RIGHT may be a
+        /// fraction such as `1/3`; may be repeated, and defaults to the puzzle's five
+        #[arg(long = "slope", value_name = "RIGHT,DOWN", value_parser = parse_slope, allow_hyphen_values = true)]
+        slopes: Vec<day3::Slope>,
+
+        /// Try every slope of whole columns going across up to RIGHT and down up to DOWN, and
+        /// report the slopes hitting the fewest and the most trees
+        #[arg(long, value_name = "RIGHT,DOWN", value_parser = parse_search_bounds, allow_hyphen_values = true, conflicts_with = "slopes")]
+        search: Option<day3::Slope>,
+
+        /// Draw the forest with each slope's path through it, `O` on open squares and `X` on trees
+        #[arg(long, conflicts_with = "search")]
+        render: bool,
+
+        /// Read the forest from PATH, or from stdin when PATH is `-`
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<PathBuf>,
+
+        /// Read the forest of this profile, from `inputs/PROFILE/day03.txt`
+        #[arg(long, default_value = DEFAULT_PROFILE)]
+        profile: String,
+
+        /// Read the example `inputs/examples/day03-NAME.txt` instead of the puzzle input
+        #[arg(long, value_name = "NAME")]
+        example: Option<String>,
+    },
+}
+
+fn parse_slope(text: &str) -> Result<day3::Slope, String> {
+    text.parse().map_err(|e: aoc_common::Error| match e {
+        aoc_common::Error::Parse(error) => error.message,
+        e => e.to_string(),
+    })
+}
+
+/// Search bounds are a slope of whole columns, since only those are searched.
+fn parse_search_bounds(text: &str) -> Result<day3::Slope, String> {
+    if text.contains('/') {
+        return Err("search bounds are whole columns, not fractions".to_string());
+    }
+    parse_slope(text)
+}
+
+/// The named example, or else the profile's puzzle input.
+fn source(profile: String, example: Option<String>) -> Source {
+    match example {
+        Some(name) => Source::Example(name),
+        None => Source::Puzzle(profile),
+    }
+}
+
+/// Where the input of `day` from `source` lives, or why it is missing.
+fn source_path(day: u8, source: &Source) -> Result<PathBuf, String> {
+    let path = source.path(day);
+    if path.exists() {
+        return Ok(path);
+    }
+
+    match source {
+        Source::Puzzle(profile) => Err(format!("Day {} has no input for profile {}: {} does not exist", day, profile, path.display())),
+        Source::Example(name) => match examples(day).as_slice() {
+            [] => Err(format!("Day {} has no examples", day)),
+            names => Err(format!("Day {} has no example {}; try {}", day, name, names.join(", "))),
+        },
+    }
+}
+
+/// Parses the input at `input`, or else the one from `source`.
+fn parse_input(solution: &days::Day, input: Option<PathBuf>, source: Source) -> Result<Box<dyn Any>, String> {
+    match input {
+        Some(path) => {
+            let puzzle_input = read_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            let parsed = solution.solver.parse(&puzzle_input);
+            parsed.map_err(|e| if path == Path::new("-") { e } else { e.in_file(&path) }).map_err(|e| e.to_string())
+        }
+        None => solution.parse_file(&source_path(solution.day, &source)?).map_err(|e| format!("Day {} input: {}", solution.day, e)),
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<PathBuf>, source: Source, format: Format) -> Result<(), String> {
+    let solution = days::get_day(day).ok_or(format!("Day {} has no solution", day))?;
+    let parsed = parse_input(solution, input, source)?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = solution.solver.solve(parsed.as_ref(), part);
+        let elapsed = start.elapsed();
+
+        match format {
+            Format::Text => println!("Day {} part {}: {}", day, part, answer),
+            Format::Json => {
+                let details = solution.solver.details(parsed.as_ref(), part);
+                println!("{}", part_json(day, part, &answer, elapsed, &details));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_every_day(part: Option<u8>, profile: &str, jobs: Option<usize>, format: Format) -> Result<(), String> {
+    let manifest = read_manifest().map_err(|e| e.to_string())?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let start = Instant::now();
+    let results = run_all(&parts, &manifest, profile, jobs.unwrap_or(0), matches!(format, Format::Json));
+    let elapsed = start.elapsed();
+
+    if let Format::Text = format {
+        println!("{:>3}  {:>4}  {:<16}  {:<24}  {:>12}  Check", "Day", "Part", "Input", "Answer", "Time");
+    }
+
+    let mut failures = 0;
+    for (day, result) in results {
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("Day {} input: {}", day, e);
+                failures += 1;
+                continue;
+            }
+        };
+
+        for part in result.parts {
+            if !part.matches() {
+                failures += 1;
+            }
+
+            match format {
+                Format::Text => {
+                    let check = match &part.expected {
+                        Some(expected) if !part.matches() => format!("MISMATCH, expected {}", expected),
+                        Some(_) => "ok".to_string(),
+                        None => "unchecked".to_string(),
+                    };
+                    println!("{:>3}  {:>4}  {:<16}  {:<24}  {:>12}  {}", day, part.part, result.source.to_string(), part.answer.to_string(), format_ms(part.elapsed), check);
+                }
+                Format::Json => {
+                    let mut value = part_json(day, part.part, &part.answer, part.elapsed, &part.details);
+                    value["input"] = result.source.to_string().into();
+                    value["expected"] = serde_json::json!(part.expected);
+                    value["matches"] = part.matches().into();
+                    println!("{}", value);
+                }
+            }
+        }
+    }
+
+    if let Format::Text = format {
+        println!("Total: {}", format_ms(elapsed));
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{} of the days and parts run failed their check against answers.txt", n)),
+    }
+}
+
+fn bench(day: Option<u8>, runs: usize, profile: &str) -> Result<(), String> {
+    let selected : Vec<&days::Day> = match day {
+        Some(day) => vec![days::get_day(day).ok_or(format!("Day {} has no solution", day))?],
+        None => days::DAYS.iter().collect(),
+    };
+    let manifest = read_manifest().map_err(|e| e.to_string())?;
+
+    println!("{:>3}  {:>12}  {:>12}  {:>12}", "Day", "Parse", "Part 1", "Part 2");
+    for day in selected {
+        let path = source_path(day.day, &bench_input(day, &manifest, profile))?;
+        let timings = time_day(day, &path, runs).map_err(|e| format!("Day {} input: {}", day.day, e))?;
+
+        println!("{:>3}  {:>12}  {:>12}  {:>12}", day.day, format_ms(timings.parse), format_ms(timings.part1), format_ms(timings.part2));
+    }
+    Ok(())
+}
+
+/// How `aoc viz` writes frames out as images.
+struct Export {
+    dir: PathBuf,
+    format: ImageFormat,
+    scale: u32,
+    palette: Option<String>,
+}
+
+fn visualize(day: u8, part: u8, input: Option<PathBuf>, source: Source, fps: u32, export: Option<Export>) -> Result<(), String> {
+    let solution = days::get_day(day).ok_or(format!("Day {} has no solution", day))?;
+    let parsed = parse_input(solution, input, source)?;
+
+    let frames = solution.solver.frames(parsed.as_ref(), part);
+    if frames.is_empty() {
+        return Err(format!("Day {} part {} has nothing to show", day, part));
+    }
+
+    if let Some(export) = export {
+        let palette : Palette = export.palette.as_deref().unwrap_or("").parse().map_err(|e: aoc_common::Error| format!("--palette {}", e))?;
+        let paths = export_frames(&frames, &export.dir, export.format, &palette, export.scale as usize)
+            .map_err(|e| format!("{}: {}", export.dir.display(), e))?;
+
+        println!("Wrote {} images to {}", paths.len(), export.dir.display());
+        Ok(())
+    } else if io::stdout().is_terminal() {
+        viz::play(frames, fps).map_err(|e| e.to_string())
+    } else {
+        let frames : Vec<String> = frames.iter().map(|frame| frame.to_string()).collect();
+        print!("{}", frames.join("\n"));
+        Ok(())
+    }
+}
+
+fn audit_passwords(policies: Option<PathBuf>, input: Option<PathBuf>, source: Source, format: AuditFormat) -> Result<(), String> {
+    let policies = match policies {
+        Some(path) => {
+            let text = read_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            day2::parse_policies(&text).map_err(|e| e.in_file(&path).to_string())?
+        }
+        None => day2::parse_policies("count-in-range\none-position").expect("the puzzle's policies parse"),
+    };
+
+    let solution = days::get_day(2).expect("day 2 is registered");
+    let parsed = parse_input(solution, input, source)?;
+    let rules = parsed.downcast_ref::<Vec<day2::Rule>>().expect("day 2 parses to rules");
+
+    let audits = day2::audit(rules, &policies);
+    match format {
+        AuditFormat::Table => print!("{}", day2::audit_table(rules, &audits)),
+        AuditFormat::Csv => print!("{}", day2::audit_csv(rules, &audits)),
+    }
+
+    let valid = audits.iter().filter(|violations| violations.is_empty()).count();
+    eprintln!("{} of {} passwords follow every policy", valid, rules.len());
+    Ok(())
+}
+
+fn find_subset(target: i64, max_size: Option<usize>, input: Option<PathBuf>, source: Source) -> Result<(), String> {
+    let solution = days::get_day(1).expect("day 1 is registered");
+    let parsed = parse_input(solution, input, source)?;
+    let entries = parsed.downcast_ref::<Vec<i64>>().expect("day 1 parses to entries");
+
+    let subset = match max_size {
+        Some(max_size) => day1::subset_sum(entries, target, max_size),
+        None => day1::smallest_subset_sum(entries, target),
+    };
+
+    match subset.map_err(|e| e.to_string())? {
+        Some(subset) => {
+            println!("{} entries sum to {}:", subset.len(), target);
+            for i in subset {
+                println!("  line {:>4}: {}", i + 1, entries[i]);
+            }
+        }
+        None => match max_size {
+            Some(max_size) => println!("No {} or fewer entries sum to {}", max_size, target),
+            None => println!("No entries sum to {}", target),
+        },
+    }
+    Ok(())
+}
+
+fn toboggan(slopes: Vec<day3::Slope>, search: Option<day3::Slope>, render: bool, input: Option<PathBuf>, source: Source) -> Result<(), String> {
+    let solution = days::get_day(3).expect("day 3 is registered");
+    let parsed = parse_input(solution, input, source)?;
+    let forest = parsed.downcast_ref::<day3::Forest>().expect("day 3 parses to a forest");
+
+    if let Some(bounds) = search {
+        let (fewest, most) = day3::search_slopes(forest, bounds.right(), bounds.down());
+        println!("Fewest trees: {}, {} trees", fewest.slope, fewest.trees);
+        println!("Most trees:   {}, {} trees", most.slope, most.trees);
+        return Ok(());
+    }
+
+    let slopes = if slopes.is_empty() { day3::PUZZLE_SLOPES.to_vec() } else { slopes };
+    let mut product : u128 = 1;
+    for slope in slopes {
+        let trees = day3::count_trees_on(forest, slope);
+        product = product.saturating_mul(trees as u128);
+        println!("{:<24}  {} trees", slope.to_string(), trees);
+        if render {
+            match day3::render_path(forest, slope) {
+                Some(path) => println!("{}", path),
+                None => println!("(too wide to draw in {} squares)", day3::MAX_RENDER_SQUARES),
+            }
+        }
+    }
+    println!("Product: {}", product);
+    Ok(())
+}
+
+fn generate_input(day: u8, size: usize, seed: u64) -> Result<(), String> {
+    let generated = generate(day, size, seed).ok_or(format!("Day {} has no generator", day))?;
+
+    print!("{}", generated.input);
+    for (part, answer) in generated.answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            eprintln!("Day {} part {}: {}", day, part + 1, answer);
+        }
+    }
+    Ok(())
+}
+
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+
+    env_logger::Builder::new().filter_level(level).parse_default_env().format_timestamp(None).init();
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+
+    let result = match cli.command {
+        Command::Run { all: true, part, profile, jobs, format, .. } => run_every_day(part, &profile, jobs, format),
+        Command::Run { day, part, input, profile, example, format, .. } => {
+            run(day.expect("clap requires a day without --all"), part, input, source(profile, example), format)
+        }
+        Command::Bench { day, runs, profile } => bench(day, runs, &profile),
+        Command::Gen { day, size, seed } => generate_input(day, size, seed),
+        Command::Viz { day, part, input, profile, example, fps, export_frames, image_format, scale, palette } => {
+            let export = export_frames.map(|dir| Export { dir, format: image_format, scale, palette });
+            visualize(day, part, input, source(profile, example), fps, export)
+        }
+        Command::Audit { policies, input, profile, example, format } => audit_passwords(policies, input, source(profile, example), format),
+        Command::Subset { target, max_size, input, profile, example } => find_subset(target, max_size, input, source(profile, example)),
+        Command::Slopes { slopes, search, render, input, profile, example } => toboggan(slopes, search, render, input, source(profile, example)),
+    };
+
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
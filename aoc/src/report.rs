@@ -0,0 +1,40 @@
+use aoc_common::{Answer, Details};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Numbers stay numbers, lists become arrays, and an unsolved part is `null`.
+pub fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(value) => json!(value),
+        Answer::UInt(value) => json!(value),
+        Answer::Text(value) => json!(value),
+        Answer::List(values) => Value::Array(values.iter().map(answer_json).collect()),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+/// One solved part as `{day, part, answer, elapsed_ms, details}`.
+pub fn part_json(day: u8, part: u8, answer: &Answer, elapsed: Duration, details: &Details) -> Value {
+    let details : serde_json::Map<String, Value> = details.iter()
+        .map(|(name, value)| (name.to_string(), answer_json(value)))
+        .collect();
+
+    json!({
+        "day": day,
+        "part": part,
+        "answer": answer_json(answer),
+        "elapsed_ms": elapsed.as_secs_f64() * 1000.0,
+        "details": details,
+    })
+}
+
+#[test]
+fn reports_parts_as_json () {
+    let details = vec![("entries", vec![1721, 299].into())];
+    let value = part_json(1, 1, &514579.into(), Duration::from_millis(2), &details);
+
+    assert_eq!(value["answer"], json!(514579));
+    assert_eq!(value["details"]["entries"], json!([1721, 299]));
+    assert_eq!(value["elapsed_ms"], json!(2.0));
+    assert_eq!(answer_json(&Answer::Unsolved), Value::Null);
+}
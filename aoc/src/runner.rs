@@ -0,0 +1,86 @@
+use crate::bench::bench_input;
+use crate::days::{Day, DAYS};
+use crate::golden::Expected;
+use crate::inputs::Source;
+use aoc_common::{Answer, Details, Result};
+use log::debug;
+use rayon::prelude::*;
+use std::time::{Duration, Instant};
+
+/// One part solved as part of a run over every day.
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// What the solver reports about the part, or nothing when details weren't asked for.
+    pub details: Details,
+    /// The answer `answers.txt` pins for the input that was solved, if any.
+    pub expected: Option<String>,
+}
+
+impl PartResult {
+    /// Whether the answer agrees with `answers.txt`; parts it has no answer for always do.
+    pub fn matches(&self) -> bool {
+        self.expected.as_ref().is_none_or(|expected| *expected == self.answer.to_string())
+    }
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub source: Source,
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Parses the input from `source` and solves `parts`, looking up what `manifest` expects for it.
+/// Each part's details are only worked out when `details` is set, since some days redo their work.
+pub fn run_day(day: &Day, source: Source, parts: &[u8], manifest: &[Expected], details: bool) -> Result<DayResult> {
+    let start = Instant::now();
+    let parsed = day.parse_file(&source.path(day.day))?;
+    let parse = start.elapsed();
+    debug!("Day {} parsed {} in {:?}", day.day, source, parse);
+
+    let parts = parts.iter().map(|part| {
+        let start = Instant::now();
+        let answer = day.solver.solve(parsed.as_ref(), *part);
+        let elapsed = start.elapsed();
+
+        let expected = manifest.iter()
+            .find(|x| x.day == day.day && x.part == *part && x.source == source)
+            .map(|x| x.answer.clone());
+
+        let details = if details { day.solver.details(parsed.as_ref(), *part) } else { Details::new() };
+        PartResult { part: *part, answer, elapsed, details, expected }
+    }).collect();
+
+    Ok(DayResult { day: day.day, source, parse, parts })
+}
+
+/// Solves every day at once on `jobs` threads, or one per core when `jobs` is 0. Each day runs
+/// on the input `aoc bench` picks for `profile`, with details when `details` is set. Results come
+/// back in day order.
+pub fn run_all(parts: &[u8], manifest: &[Expected], profile: &str, jobs: usize, details: bool) -> Vec<(u8, Result<DayResult>)> {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build().expect("thread pool starts");
+
+    pool.install(|| {
+        DAYS.par_iter()
+            .map(|day| (day.day, run_day(day, bench_input(day, manifest, profile), parts, manifest, details)))
+            .collect()
+    })
+}
+
+#[test]
+fn checks_answers_against_the_manifest () {
+    let day = crate::days::get_day(1).unwrap();
+    let source = Source::Puzzle(crate::inputs::DEFAULT_PROFILE.to_string());
+    let manifest = vec![Expected { day: 1, part: 1, source: source.clone(), answer: "0".to_string() }];
+
+    let result = run_day(day, source.clone(), &[1, 2], &manifest, false).unwrap();
+
+    assert_eq!(result.parts[0].expected.as_deref(), Some("0"));
+    assert!(result.parts[0].details.is_empty());
+    assert!(!result.parts[0].matches());
+    assert_eq!(result.parts[1].expected, None);
+    assert!(result.parts[1].matches());
+    assert!(!run_day(day, source, &[1], &manifest, true).unwrap().parts[0].details.is_empty());
+}
@@ -0,0 +1,66 @@
+use aoc_common::{Control, Frame, Player};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, terminal};
+use std::io::{self, Write};
+use std::time::Duration;
+
+const KEYS: &str = "←/→ step  g/G first/last  space play/pause  +/- speed  z/Z w/W layer  q quit";
+
+/// What a key press does, where `None` means quit.
+fn key_control(key: KeyEvent) -> Option<Option<Control>> {
+    let control = match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return None,
+        KeyCode::Char('q') | KeyCode::Esc => return None,
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('n') => Control::Next,
+        KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('p') => Control::Previous,
+        KeyCode::Home | KeyCode::Char('g') => Control::First,
+        KeyCode::End | KeyCode::Char('G') => Control::Last,
+        KeyCode::Char(' ') => Control::TogglePlay,
+        KeyCode::Char('+') | KeyCode::Char('=') => Control::Faster,
+        KeyCode::Char('-') => Control::Slower,
+        KeyCode::Up | KeyCode::Char('z') => Control::Layer(1, 0),
+        KeyCode::Down | KeyCode::Char('Z') => Control::Layer(-1, 0),
+        KeyCode::Char('w') => Control::Layer(0, 1),
+        KeyCode::Char('W') => Control::Layer(0, -1),
+        _ => return Some(None),
+    };
+    Some(Some(control))
+}
+
+fn show(player: &mut Player, stdout: &mut io::Stdout) -> io::Result<()> {
+    loop {
+        write!(stdout, "{}{}", player.render(), KEYS)?;
+        stdout.flush()?;
+
+        let timeout = if player.is_playing() { player.delay() } else { Duration::from_secs(60) };
+        if !event::poll(timeout)? {
+            player.tick();
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                match key_control(key) {
+                    Some(Some(control)) => player.control(control),
+                    Some(None) => {}
+                    None => return Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Shows `frames` full screen until the viewer quits, starting paused on the first one.
+pub fn play(frames: Vec<Frame>, fps: u32) -> io::Result<()> {
+    let mut player = Player::new(frames, fps);
+    let mut stdout = io::stdout();
+
+    terminal::enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = show(&mut player, &mut stdout);
+
+    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
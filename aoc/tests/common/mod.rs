@@ -0,0 +1,11 @@
+//! What the integration tests share: one test per day, so a failing day is named on its own.
+
+/// Declares a test for each `<name>: <day>, <args>...;`, calling `$check(<day>, <args>...)`.
+macro_rules! day_tests {
+    ($check:ident; $($name:ident: $day:expr $(, $arg:expr)*;)*) => {
+        $(#[test]
+        fn $name () {
+            $check($day $(, $arg)*);
+        })*
+    }
+}
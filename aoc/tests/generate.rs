@@ -0,0 +1,55 @@
+#[macro_use]
+mod common;
+
+use aoc::days::get_day;
+use aoc::generate::generate;
+
+fn check_day(day: u8, size: usize) {
+    let solver = get_day(day).unwrap().solver;
+
+    for seed in 0..3 {
+        let generated = generate(day, size, seed).unwrap();
+        let parsed = solver.parse(&generated.input)
+            .unwrap_or_else(|e| panic!("day {} seed {} does not parse:\n{}", day, seed, e));
+
+        for (part, expected) in generated.answers.iter().enumerate() {
+            if let Some(expected) = expected {
+                let answer = solver.solve(parsed.as_ref(), part as u8 + 1).to_string();
+                assert_eq!(&answer, expected, "day {} part {} seed {}", day, part + 1, seed);
+            }
+        }
+    }
+}
+
+#[test]
+fn same_seed_same_input () {
+    for day in 1..=20 {
+        assert_eq!(generate(day, 40, 7).unwrap().input, generate(day, 40, 7).unwrap().input);
+    }
+    assert_ne!(generate(1, 40, 7).unwrap().input, generate(1, 40, 8).unwrap().input);
+}
+
+day_tests! {
+    check_day;
+    day1: 1, 200;
+    day2: 2, 200;
+    day3: 3, 200;
+    day4: 4, 200;
+    day5: 5, 200;
+    day6: 6, 200;
+    day7: 7, 20;
+    day8: 8, 200;
+    day9: 9, 200;
+    day10: 10, 100;
+    day11: 11, 20;
+    day12: 12, 200;
+    day13: 13, 9;
+    day14: 14, 200;
+    day15: 15, 6;
+    day16: 16, 200;
+    day17: 17, 6;
+    day18: 18, 200;
+    day19: 19, 200;
+    day20: 20, 9;
+    day20_largest: 20, 225;
+}
@@ -0,0 +1,47 @@
+#[macro_use]
+mod common;
+
+use aoc::golden::{read_manifest, Expected};
+
+fn check_day(day: u8) {
+    let manifest = read_manifest().unwrap();
+    let expected : Vec<&Expected> = manifest.iter().filter(|x| x.day == day).collect();
+
+    assert!(!expected.is_empty(), "answers.txt has no answers for day {}", day);
+
+    let mismatches : Vec<String> = expected.iter().filter_map(|x| {
+        let answer = x.solve().unwrap_or_else(|e| panic!("{}", e)).to_string();
+
+        if answer == x.answer {
+            None
+        } else {
+            Some(format!("day {} part {} ({}): expected {}, got {}", day, x.part, x.source, x.answer, answer))
+        }
+    }).collect();
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+day_tests! {
+    check_day;
+    day1: 1;
+    day2: 2;
+    day3: 3;
+    day4: 4;
+    day5: 5;
+    day6: 6;
+    day7: 7;
+    day8: 8;
+    day9: 9;
+    day10: 10;
+    day11: 11;
+    day12: 12;
+    day13: 13;
+    day14: 14;
+    day15: 15;
+    day16: 16;
+    day17: 17;
+    day18: 18;
+    day19: 19;
+    day20: 20;
+}
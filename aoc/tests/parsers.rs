@@ -0,0 +1,81 @@
+//! Every day's parser must turn any text into either its input or a parse error, never a panic.
+
+#[macro_use]
+mod common;
+
+use aoc::days::get_day;
+use aoc_common::Error;
+use proptest::prelude::*;
+use proptest::string::string_regex;
+use proptest::test_runner::{Config, TestRunner};
+
+fn check_parse(day: u8, input: &str) -> Result<(), TestCaseError> {
+    match get_day(day).unwrap().solver.parse(input) {
+        Ok(_) | Err(Error::Parse(_)) => Ok(()),
+        Err(error) => Err(TestCaseError::fail(format!("day {}: expected a parse error, got {}", day, error))),
+    }
+}
+
+/// Parses any text and text matching `pattern`, which is nearer what `day` expects.
+fn check_parser(day: u8, pattern: &str) {
+    let inputs = prop_oneof![any::<String>(), string_regex(pattern).unwrap()];
+    let mut runner = TestRunner::new(Config { source_file: Some(file!()), ..Config::default() });
+
+    if let Err(error) = runner.run(&inputs, |input| check_parse(day, &input)) {
+        panic!("day {}: {}", day, error);
+    }
+}
+
+/// Solves both parts of whatever parses, for days whose solvers used to index past their input.
+fn check_solve(day: u8, input: &str) {
+    let solver = get_day(day).unwrap().solver;
+
+    if let Ok(parsed) = solver.parse(input) {
+        solver.solve(parsed.as_ref(), 1);
+        solver.solve(parsed.as_ref(), 2);
+    }
+}
+
+/// Rules where the second half of rule 0 can run out of message, and the rules part 2 needs.
+const DAY19_RULES : &str = "0: 2 1\n1: \"a\"\n2: 1 1 | 3\n3: \"b\"\n8: 42\n11: 42 31\n42: 1 3\n31: 3 1\n\n";
+
+day_tests! {
+    check_parser;
+    day1: 1, r"(-?[0-9]{1,12}\n){0,5}";
+    day2: 2, r"([0-9]{1,3}-[0-9]{1,3} [a-zé:🦀]{1,2}: [a-zé: ]{0,8}\n){0,5}";
+    day3: 3, r"([#.x]{0,8}\n){0,5}";
+    day4: 4, r"(([a-z]{3}:[#a-z0-9:]{0,6} ?){0,4}\n\n?){0,5}";
+    day5: 5, r"([FBLRé]{0,11}\n){0,5}";
+    day6: 6, r"([a-zA]{0,5}\n\n?){0,6}";
+    day7: 7, r"([a-z]{1,5} [a-z]{1,5} bags contain ([0-9]{1,11} [a-z]{1,5} [a-z]{1,5} bags?(, )?|no other bags){0,2}\.?\n){0,4}";
+    day8: 8, r"((nop|acc|jmp|xyz) [-+]?[0-9]{1,12}\n){0,5}";
+    day9: 9, r"(-?[0-9]{1,20}\n){0,30}";
+    day10: 10, r"(-?[0-9]{1,20}\n){0,8}";
+    day11: 11, r"([.L#x]{0,6}\n){0,5}";
+    day12: 12, r"([NSEWLRFé][0-9]{0,12}\n){0,6}";
+    day13: 13, r"[0-9]{0,12}\n?([0-9x]{0,12},?){0,6}";
+    day14: 14, r"((mask = [01X]{30,37}|mem\[[0-9]{1,21}\] = [0-9X]{1,21})\n){0,5}";
+    day15: 15, r"([0-9]{0,21},?){0,6}\n?";
+    day16: 16, r"([a-z ]{1,8}: [0-9]{1,11}-[0-9]{1,11} or [0-9]{1,11}-[0-9]{1,11}\n){0,3}\nyour ticket:\n([0-9]{1,11},?){0,4}\n\nnearby tickets:(\n[0-9,]{0,8}){0,3}";
+    day17: 17, r"([.#x]{0,6}\n){0,5}";
+    day18: 18, r"([0-9+*() x]{0,12}\n){0,4}";
+    day19: 19, r#"([0-9]{1,11}: ("[ab]{0,2}"?|[0-9]{1,3}( [0-9]{1,3})?( ?\|? [0-9]{1,3})?)\n){0,5}\n([ab]{0,6}\n){0,3}"#;
+    day20: 20, r"(Tile [0-9]{1,11}:?\n([.#]{9,11}\n){0,11}\n?){0,3}";
+}
+
+proptest! {
+    #[test]
+    fn day2_solves_any_policy (input in r"([0-9]{1,2}-[0-9]{1,2} [a-cé]: [a-cé]{0,8}\n){0,5}") {
+        check_solve(2, &input);
+    }
+
+    #[test]
+    fn day5_solves_any_passes (input in r"([FB]{7}[LR]{3}\n){0,20}") {
+        check_solve(5, &input);
+    }
+
+    #[test]
+    fn day19_solves_any_message (messages in r"([ab]{1,8}\n){0,5}") {
+        check_solve(19, &format!("{}{}", DAY19_RULES, messages));
+    }
+}
@@ -0,0 +1,402 @@
+use aoc_common::{parse_lines, parse_number, Answer, Details, Result, Solution};
+#[cfg(not(feature = "bigint"))]
+use log::error;
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+use std::collections::{HashMap, HashSet};
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+
+/// Whether `find_k_sum` stops at the first combination of entries it finds, or finds them all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Matches {
+    First,
+    All,
+}
+
+/// Pairs of entries summing to `target`, found by looking up what each entry still needs among
+/// the ones before it.
+fn find_pairs(values: &[i64], target: i128, matches: Matches) -> Vec<Vec<usize>> {
+    let mut first_index : HashMap<i64, usize> = HashMap::new();
+    let mut found : HashSet<(i64, i64)> = HashSet::new();
+    let mut pairs = Vec::new();
+
+    for (j, value) in values.iter().enumerate() {
+        let needed = target - *value as i128;
+
+        if let Some(i) = i64::try_from(needed).ok().and_then(|needed| first_index.get(&needed)) {
+            if found.insert((values[*i].min(*value), values[*i].max(*value))) {
+                pairs.push(vec![*i, j]);
+                if matches == Matches::First {
+                    break;
+                }
+            }
+        }
+        first_index.entry(*value).or_insert(j);
+    }
+    pairs
+}
+
+/// Combinations of `k` entries from `sorted[start..]` summing to `target`, each pushed onto
+/// `combination` after the entries already picked. Entries are `(value, index)` sorted by value, and
+/// equal values are only tried once in each place, so each combination of values is found once.
+fn find_sorted(sorted: &[(i64, usize)], start: usize, k: usize, target: i128, combination: &mut Vec<usize>,
+               combinations: &mut Vec<Vec<usize>>, matches: Matches) {
+    if k == 2 {
+        let (mut left, mut right) = (start, sorted.len() - 1);
+
+        while left < right {
+            let sum = sorted[left].0 as i128 + sorted[right].0 as i128;
+
+            if sum == target {
+                let mut found = combination.clone();
+                found.extend([sorted[left].1, sorted[right].1]);
+                combinations.push(found);
+                if matches == Matches::First {
+                    return;
+                }
+
+                while left < right && sorted[left].0 == sorted[left + 1].0 {
+                    left += 1;
+                }
+                while left < right && sorted[right].0 == sorted[right - 1].0 {
+                    right -= 1;
+                }
+                left += 1;
+                right -= 1;
+            } else if sum < target {
+                left += 1;
+            } else {
+                right -= 1;
+            }
+        }
+        return;
+    }
+
+    for first in start..=sorted.len() - k {
+        if first > start && sorted[first].0 == sorted[first - 1].0 {
+            continue;
+        }
+
+        combination.push(sorted[first].1);
+        find_sorted(sorted, first + 1, k - 1, target - sorted[first].0 as i128, combination, combinations, matches);
+        combination.pop();
+
+        if matches == Matches::First && !combinations.is_empty() {
+            return;
+        }
+    }
+}
+
+/// Finds `k` different entries of `values` that sum to `target`, as their indices in ascending
+/// order. The same value may be used as often as it appears. With `Matches::All`, every combination
+/// of values is returned once, sorted, and entries with equal values give the same combination.
+///
+/// Pairs are found by hashing in one pass; larger combinations by sorting and closing in on each
+/// remaining sum from both ends, in O(n^(k-1)). Sums are worked out in `i128`, so never overflow.
+pub fn find_k_sum(values: &[i64], k: usize, target: i64, matches: Matches) -> Vec<Vec<usize>> {
+    let mut combinations = match k {
+        0 if target == 0 => vec![Vec::new()],
+        0 => Vec::new(),
+        _ if k > values.len() => Vec::new(),
+        1 => values.iter().position(|x| *x == target).map(|i| vec![i]).into_iter().collect(),
+        2 => find_pairs(values, target as i128, matches),
+        _ => {
+            let mut sorted : Vec<(i64, usize)> = values.iter().cloned().zip(0..).collect();
+            sorted.sort();
+
+            let mut combinations = Vec::new();
+            find_sorted(&sorted, 0, k, target as i128, &mut Vec::new(), &mut combinations, matches);
+            combinations
+        }
+    };
+
+    for combination in combinations.iter_mut() {
+        combination.sort();
+    }
+    combinations.sort();
+    combinations
+}
+
+/// The most bits `smallest_subset_sum` keeps while it searches: one for each entry and sum, to
+/// retrace the subset, and 64 for each sum, to count the fewest entries reaching it so far.
+pub const MAX_BITS: u128 = 1 << 28;
+
+/// Entries spanning too wide a range of sums to search for subsets of.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TooManySums {
+    pub bits: u128,
+}
+
+impl fmt::Display for TooManySums {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "finding a subset means keeping {} bits across the sums, more than the {} allowed", self.bits, MAX_BITS)
+    }
+}
+
+impl std::error::Error for TooManySums {}
+
+/// For each of the `sums` sums from `lowest`, the fewest entries of `values` that add up to it, or
+/// `None` when none do. Alongside it, a bit for each entry and sum, at `entry * sums + sum`, set
+/// where taking that entry lowered the fewest of the entries up to it that reach the sum.
+fn fewest_entries(values: &[i64], lowest: i128, sums: usize) -> (Vec<Option<u32>>, Vec<u64>) {
+    let mut fewest = vec![None; sums];
+    fewest[(-lowest) as usize] = Some(0);
+    let mut taken = vec![0u64; (values.len() * sums).div_ceil(64)];
+
+    for (i, value) in values.iter().enumerate() {
+        // Going away from the sums each one is reached from, so each entry is only taken once.
+        let order : Box<dyn Iterator<Item=usize>> = if *value > 0 { Box::new((0..sums).rev()) } else { Box::new(0..sums) };
+
+        for s in order {
+            let with = (s as i128 - *value as i128)
+                .try_into().ok()
+                .and_then(|from: usize| fewest.get(from).cloned().flatten())
+                .map(|count| count + 1);
+
+            if let Some(count) = with {
+                if fewest[s].is_none_or(|fewest| count < fewest) {
+                    fewest[s] = Some(count);
+                    let bit = i * sums + s;
+                    taken[bit / 64] |= 1 << (bit % 64);
+                }
+            }
+        }
+    }
+    (fewest, taken)
+}
+
+/// The indices, in ascending order, of the fewest entries of `values` that sum to `target`, if
+/// any do. Each entry is used at most once, and no entries sum to 0.
+///
+/// Works through every reachable sum in turn, so takes O(n × range) time and bits of space, where
+/// the range runs from `target` or the sum of the negative entries up to the sum of the positive
+/// ones. When that comes to more than `MAX_BITS`, it gives up with an error instead.
+pub fn smallest_subset_sum(values: &[i64], target: i64) -> std::result::Result<Option<Vec<usize>>, TooManySums> {
+    let target = target as i128;
+    let negative : i128 = values.iter().filter(|x| **x < 0).map(|x| *x as i128).sum();
+    let positive : i128 = values.iter().filter(|x| **x > 0).map(|x| *x as i128).sum();
+    if target < negative || target > positive {
+        return Ok(None);
+    }
+
+    // Without negative entries, no sum past the target can come back down to it.
+    let highest = if negative == 0 { target } else { positive };
+    let width = (highest - negative + 1) as u128;
+    let bits = width.saturating_mul(values.len() as u128 + 64);
+    if bits > MAX_BITS {
+        return Err(TooManySums { bits });
+    }
+    let width = width as usize;
+    let (fewest, taken) = fewest_entries(values, negative, width);
+
+    let mut sum = (target - negative) as usize;
+    if fewest[sum].is_none() {
+        return Ok(None);
+    }
+
+    let mut subset = Vec::new();
+    for i in (0..values.len()).rev() {
+        let bit = i * width + sum;
+        if taken[bit / 64] >> (bit % 64) & 1 == 1 {
+            subset.push(i);
+            sum = (sum as i128 - values[i] as i128) as usize;
+        }
+    }
+    subset.reverse();
+    Ok(Some(subset))
+}
+
+/// The indices of at most `max_size` entries of `values` that sum to `target`, if any do.
+pub fn subset_sum(values: &[i64], target: i64, max_size: usize) -> std::result::Result<Option<Vec<usize>>, TooManySums> {
+    Ok(smallest_subset_sum(values, target)?.filter(|subset| subset.len() <= max_size))
+}
+
+/// The values of the first `k` entries found that sum to 2020.
+fn balance_books(values: &[i64], k: usize) -> Option<Vec<i64>> {
+    let combination = find_k_sum(values, k, 2020, Matches::First).pop()?;
+
+    Some(combination.iter().map(|i| values[*i]).collect())
+}
+
+/// Entries whose product does not fit in an `i64`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub entries: Vec<i64>,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries : Vec<String> = self.entries.iter().map(|x| x.to_string()).collect();
+        write!(f, "the product of entries {} overflows a 64-bit integer", entries.join(", "))
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Multiplies the entries together, or reports them if the product does not fit in an `i64`.
+pub fn product(entries: &[i64]) -> std::result::Result<i64, Overflow> {
+    entries.iter()
+        .try_fold(1i64, |product, x| product.checked_mul(*x))
+        .ok_or_else(|| Overflow { entries: entries.to_vec() })
+}
+
+/// The product of the entries, written out in full when it does not fit in an `i64`.
+#[cfg(feature = "bigint")]
+fn answer(entries: Option<Vec<i64>>) -> Answer {
+    entries.map_or(Answer::Unsolved, |x| match product(&x) {
+        Ok(product) => product.into(),
+        Err(_) => x.iter().map(|x| BigInt::from(*x)).product::<BigInt>().to_string().into(),
+    })
+}
+
+/// The product of the entries, or no answer, after logging the entries, when it overflows.
+#[cfg(not(feature = "bigint"))]
+fn answer(entries: Option<Vec<i64>>) -> Answer {
+    entries.map_or(Answer::Unsolved, |x| match product(&x) {
+        Ok(product) => product.into(),
+        Err(overflow) => {
+            error!("{}; build with `--features bigint` for the full answer", overflow);
+            Answer::Unsolved
+        }
+    })
+}
+
+fn as_int(line: &str) -> Result<i64> {
+    parse_number(line, line)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        parse_lines(input, as_int)
+    }
+
+    fn part1(values: &Vec<i64>) -> Answer {
+        answer(balance_books(values, 2))
+    }
+
+    fn part2(values: &Vec<i64>) -> Answer {
+        answer(balance_books(values, 3))
+    }
+
+    /// The entries picked, and without the `bigint` feature, why there is no answer when their
+    /// product overflows.
+    fn details(values: &Vec<i64>, part: u8) -> Details {
+        let entries = match balance_books(values, part as usize + 1) {
+            Some(entries) => entries,
+            None => return Vec::new(),
+        };
+
+        let mut details = vec![("entries", entries.clone().into())];
+        if cfg!(not(feature = "bigint")) {
+            if let Err(overflow) = product(&entries) {
+                details.push(("overflow", overflow.to_string().into()));
+            }
+        }
+        details
+    }
+}
+
+#[test]
+fn finds_the_example_entries () {
+    let values = [1721, 979, 366, 299, 675, 1456];
+
+    assert_eq!(find_k_sum(&values, 2, 2020, Matches::First), vec![vec![0, 3]]);
+    assert_eq!(find_k_sum(&values, 3, 2020, Matches::First), vec![vec![1, 2, 4]]);
+}
+
+#[test]
+fn uses_each_entry_at_most_once () {
+    assert_eq!(find_k_sum(&[1010, 5], 2, 2020, Matches::All), Vec::<Vec<usize>>::new());
+    assert_eq!(find_k_sum(&[1010, 5, 1010], 2, 2020, Matches::All), vec![vec![0, 2]]);
+    assert_eq!(find_k_sum(&[700, 620, 5], 3, 2020, Matches::All), Vec::<Vec<usize>>::new());
+    assert_eq!(find_k_sum(&[700, 620, 5, 700], 3, 2020, Matches::All), vec![vec![0, 1, 3]]);
+}
+
+#[test]
+fn finds_each_combination_of_values_once () {
+    let values = [1, 2, 3, 4, 2, 5];
+
+    assert_eq!(find_k_sum(&values, 2, 6, Matches::All), vec![vec![0, 5], vec![1, 3]]);
+    assert_eq!(find_k_sum(&values, 3, 8, Matches::All), vec![vec![0, 1, 5], vec![0, 2, 3], vec![1, 3, 4]]);
+    assert_eq!(find_k_sum(&values, 4, 10, Matches::All), vec![vec![0, 1, 2, 3], vec![0, 1, 4, 5]]);
+    assert_eq!(find_k_sum(&values, 4, 10, Matches::First).len(), 1);
+}
+
+#[test]
+fn handles_small_k () {
+    assert_eq!(find_k_sum(&[3, 7], 0, 0, Matches::All), vec![Vec::<usize>::new()]);
+    assert_eq!(find_k_sum(&[3, 7, 7], 1, 7, Matches::All), vec![vec![1]]);
+    assert_eq!(find_k_sum(&[3, 7], 3, 10, Matches::All), Vec::<Vec<usize>>::new());
+}
+
+#[test]
+fn finds_the_smallest_subset () {
+    let values = [1721, 979, 366, 299, 675, 1456];
+
+    assert_eq!(smallest_subset_sum(&values, 2020).unwrap(), Some(vec![0, 3]));
+    assert_eq!(smallest_subset_sum(&values, 979 + 366 + 675).unwrap(), Some(vec![0, 3]));
+    assert_eq!(smallest_subset_sum(&values, 979 + 366 + 299).unwrap(), Some(vec![1, 2, 3]));
+    assert_eq!(smallest_subset_sum(&values, 0).unwrap(), Some(vec![]));
+    assert_eq!(smallest_subset_sum(&values, 1).unwrap(), None);
+    assert_eq!(smallest_subset_sum(&values, 10000).unwrap(), None);
+}
+
+#[test]
+fn bounds_subset_size () {
+    let values = [5, 5, 5, 5, 12];
+
+    assert_eq!(subset_sum(&values, 20, 4).unwrap(), Some(vec![0, 1, 2, 3]));
+    assert_eq!(subset_sum(&values, 20, 3).unwrap(), None);
+    assert_eq!(subset_sum(&values, 17, 2).unwrap(), Some(vec![0, 4]));
+}
+
+#[test]
+fn finds_subsets_with_negative_entries () {
+    let values = [-3, 10, 7, -8];
+
+    assert_eq!(smallest_subset_sum(&values, -1).unwrap(), Some(vec![2, 3]));
+    assert_eq!(smallest_subset_sum(&values, -11).unwrap(), Some(vec![0, 3]));
+    assert_eq!(smallest_subset_sum(&values, 6).unwrap(), Some(vec![0, 1, 2, 3]));
+    assert_eq!(smallest_subset_sum(&values, 18).unwrap(), None);
+}
+
+#[test]
+fn reports_overflowing_products () {
+    assert_eq!(product(&[1721, 299]), Ok(514579));
+    assert_eq!(product(&[i64::MAX, -1]), Ok(-i64::MAX));
+
+    let overflow = product(&[1 << 40, 1 << 30, 3]).unwrap_err();
+    assert_eq!(overflow.entries, vec![1 << 40, 1 << 30, 3]);
+    assert_eq!(overflow.to_string(), "the product of entries 1099511627776, 1073741824, 3 overflows a 64-bit integer");
+}
+
+#[cfg(not(feature = "bigint"))]
+#[test]
+fn leaves_overflowing_answers_unsolved () {
+    let values = vec![(1 << 40) + 2020, -(1 << 40)];
+
+    assert_eq!(answer(Some(vec![1 << 40, 1 << 30])), Answer::Unsolved);
+    assert_eq!(Day1::details(&values, 1), vec![
+        ("entries", values.clone().into()),
+        ("overflow", "the product of entries 1099511629796, -1099511627776 overflows a 64-bit integer".to_string().into()),
+    ]);
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn writes_out_big_answers () {
+    assert_eq!(answer(Some(vec![1 << 40, 1 << 30])).to_string(), "1180591620717411303424");
+    assert_eq!(answer(Some(vec![1721, 299])).to_string(), "514579");
+}
+
+#[test]
+fn refuses_subsets_spanning_too_many_sums () {
+    assert_eq!(smallest_subset_sum(&[-1, 1 << 40], 5), Err(TooManySums { bits: ((1 << 40) + 2) * 66 }));
+    assert!(smallest_subset_sum(&[i64::MIN, i64::MAX], -1).is_err());
+    assert_eq!(smallest_subset_sum(&[1 << 40, 3], 3), Ok(Some(vec![1])));
+}
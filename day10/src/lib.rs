@@ -0,0 +1,57 @@
+use aoc_common::{parse_lines, parse_number, Answer, Result, Solution};
+use log::error;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        let mut values : Vec<i64> = parse_lines(input, |line| parse_number(line, line))?;
+        values.sort();
+        Ok(values)
+    }
+
+    fn part1(values: &Vec<i64>) -> Answer {
+        let mut one_jumps = 0;
+        let mut three_jumps = 1;
+
+        let mut joltage = 0;
+        for value in values {
+            match value - joltage {
+                1 => one_jumps+=1,
+                2 => (),
+                3 => three_jumps+=1,
+                _ => {
+                    error!("No adapter takes {} jolts to {}", joltage, value);
+                    return Answer::Unsolved;
+                }
+            };
+            joltage = *value;
+        }
+
+        (one_jumps * three_jumps).into()
+    }
+
+    fn part2(values: &Vec<i64>) -> Answer {
+        let mut values = values.clone();
+        values.insert(0, 0);
+
+        let mut options_vec : Vec<i64> = Vec::new();
+        options_vec.push(1);
+        
+        for i in 1..values.len() {
+            let value = values[i];
+            let options : i64 = [value-1, value-2, value-3].iter().map(|x| {
+                match values.iter().position(|y| x==y) {
+                    Some(index) => options_vec[index],
+                    None => 0
+                }
+            }).sum();
+
+            options_vec.push(options);
+        }
+
+        (*options_vec.last().unwrap()).into()
+    }
+}
@@ -0,0 +1,116 @@
+use aoc_common::{grid::NEIGHBOURS8, Answer, Cell, Frame, Grid, Result, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GridPosition {
+    Seat { occupied: bool },
+    Floor,
+}
+
+impl Cell for GridPosition {
+    const EXPECTED: &'static str = "`.`, `L` or `#`";
+
+    fn from_char(c: char) -> Option<GridPosition> {
+        match c {
+            '.' => Some(GridPosition::Floor),
+            'L' => Some(GridPosition::Seat{occupied:false}),
+            '#' => Some(GridPosition::Seat{occupied:true}),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            GridPosition::Floor => '.',
+            GridPosition::Seat{occupied:false} => 'L',
+            GridPosition::Seat{occupied:true} => '#',
+        }
+    }
+}
+
+fn is_occupied(position: &GridPosition) -> bool {
+    *position == GridPosition::Seat{occupied:true}
+}
+
+fn count_occupied_adjacent(grid: &Grid<GridPosition>, x: usize, y: usize) -> usize {
+    grid.neighbours8(x, y).filter(|pos| is_occupied(&grid[*pos])).count()
+}
+
+/// Counts the occupied seats that are the first seat seen in each direction.
+fn count_occupied_visible(grid: &Grid<GridPosition>, x: usize, y: usize) -> usize {
+    NEIGHBOURS8.iter()
+        .filter_map(|direction| grid.ray(x, y, *direction).find(|pos| grid[*pos] != GridPosition::Floor))
+        .filter(|pos| is_occupied(&grid[*pos]))
+        .count()
+}
+
+/// Seats and unseats everyone at once. A seat empties once `tolerance` of the neighbours counted by
+/// `count_occupied` are occupied, and fills when none of them are.
+fn next_generation<F>(grid: &Grid<GridPosition>, count_occupied: &F, tolerance: usize) -> Grid<GridPosition>
+where F: Fn(&Grid<GridPosition>, usize, usize) -> usize {
+    Grid::from_fn(grid.width(), grid.height(), |x, y| {
+        match grid[(x, y)] {
+            GridPosition::Seat{occupied:true} => GridPosition::Seat{occupied:count_occupied(grid, x, y) < tolerance},
+            GridPosition::Seat{occupied:false} => GridPosition::Seat{occupied:count_occupied(grid, x, y) == 0},
+            GridPosition::Floor => GridPosition::Floor,
+        }
+    })
+}
+
+/// Seats people until nobody moves, and returns how many seats end up occupied.
+fn settle<F>(grid: &Grid<GridPosition>, count_occupied: F, tolerance: usize) -> usize
+where F: Fn(&Grid<GridPosition>, usize, usize) -> usize {
+    let mut current_state = grid.clone();
+
+    loop {
+        let next_state = next_generation(&current_state, &count_occupied, tolerance);
+
+        if next_state == current_state {
+            return current_state.iter().filter(|position| is_occupied(position)).count();
+        }
+        current_state = next_state;
+    }
+}
+
+/// Every generation from the initial seating up to the one where nobody moves.
+fn generations<F>(grid: &Grid<GridPosition>, count_occupied: F, tolerance: usize) -> Vec<Frame>
+where F: Fn(&Grid<GridPosition>, usize, usize) -> usize {
+    let mut frames = Vec::new();
+    let mut current_state = grid.clone();
+
+    loop {
+        let occupied = current_state.iter().filter(|position| is_occupied(position)).count();
+        frames.push(Frame::from_grid(format!("Round {}: {} occupied", frames.len(), occupied), &current_state));
+
+        let next_state = next_generation(&current_state, &count_occupied, tolerance);
+        if next_state == current_state {
+            return frames;
+        }
+        current_state = next_state;
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<GridPosition>;
+
+    fn parse(input: &str) -> Result<Grid<GridPosition>> {
+        input.parse()
+    }
+
+    fn part1(grid: &Grid<GridPosition>) -> Answer {
+        settle(grid, count_occupied_adjacent, 4).into()
+    }
+
+    fn part2(grid: &Grid<GridPosition>) -> Answer {
+        settle(grid, count_occupied_visible, 5).into()
+    }
+
+    fn frames(grid: &Grid<GridPosition>, part: u8) -> Vec<Frame> {
+        match part {
+            1 => generations(grid, count_occupied_adjacent, 4),
+            2 => generations(grid, count_occupied_visible, 5),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_common::{parse_lines, parse_number, Answer, Error, Result, Solution};
+
+struct State1 {
+    x: f64,
+    y: f64,
+    heading: f64,
+}
+
+#[derive(Debug)]
+struct State2 {
+    x: f64,
+    y: f64,
+    wx: f64,
+    wy: f64,
+}
+
+#[derive(Debug)]
+pub enum ActionEnum {
+    North{value:f64},
+    South{value:f64},
+    East{value:f64},
+    West{value:f64},
+    Left{value:f64},
+    Right{value:f64},
+    Forward{value:f64},
+}
+
+fn update1(state: &State1, action: &ActionEnum) -> State1 {
+
+    match action {
+        ActionEnum::North{value} => State1{ x:state.x, 
+                                            y:state.y + value, 
+                                            heading:state.heading},
+
+        ActionEnum::South{value} => State1{ x:state.x, 
+                                            y:state.y - value, 
+                                            heading:state.heading},
+
+        ActionEnum::East{value}  => State1{ x:state.x + value, 
+                                            y:state.y, 
+                                            heading:state.heading},
+                                           
+        ActionEnum::West{value}  => State1{ x:state.x - value, 
+                                            y:state.y, 
+                                            heading:state.heading},
+
+        ActionEnum::Left{value}  => State1{ x:state.x, 
+                                            y:state.y, 
+                                            heading:state.heading + value},
+
+        ActionEnum::Right{value} => State1{ x:state.x, 
+                                            y:state.y, 
+                                            heading:state.heading - value},
+
+        ActionEnum::Forward{value} => State1{ x:state.x + state.heading.to_radians().cos() * value, 
+                                              y:state.y + state.heading.to_radians().sin() * value,  
+                                              heading:state.heading},    
+    }
+}
+
+fn parse_action(string: &str) -> Result<ActionEnum> {
+    let action = string.chars().next()
+        .ok_or_else(|| Error::parse(string, string, "expected `<action><value>`"))?;
+    let (action_str, value_str) = string.split_at(action.len_utf8());
+    let value = parse_number::<f64>(string, value_str)?;
+
+    match action {
+        'N' => Ok(ActionEnum::North{value}),
+        'S' => Ok(ActionEnum::South{value}),
+        'E' => Ok(ActionEnum::East{value}),
+        'W' => Ok(ActionEnum::West{value}),
+        'L' => Ok(ActionEnum::Left{value}),
+        'R' => Ok(ActionEnum::Right{value}),
+        'F' => Ok(ActionEnum::Forward{value}),
+        _ => Err(Error::parse(string, action_str, format!("Unknown Action `{}`", action))),
+    }
+}
+
+fn rotate(state: &State2, angle_degrees:f64) -> State2 {
+    let r = (state.wx.powf(2.0) + state.wy.powf(2.0)).sqrt();
+    let theta = state.wy.atan2(state.wx) + angle_degrees.to_radians();
+
+    State2{ x:state.x,
+            y:state.y,
+            wx:theta.cos() * r,
+            wy:theta.sin() * r}
+}
+
+fn update2(state: &State2, action: &ActionEnum) -> State2 {
+
+    match action {
+        ActionEnum::North{value} => State2{ x:state.x, 
+                                            y:state.y,
+                                            wx:state.wx,
+                                            wy:state.wy + value},
+
+        ActionEnum::South{value} => State2{ x:state.x, 
+                                            y:state.y,
+                                            wx:state.wx,
+                                            wy:state.wy - value},
+
+        ActionEnum::East{value}  => State2{ x:state.x, 
+                                            y:state.y,
+                                            wx:state.wx + value,
+                                            wy:state.wy},
+                                           
+        ActionEnum::West{value}  => State2{ x:state.x, 
+                                            y:state.y,
+                                            wx:state.wx - value,
+                                            wy:state.wy},
+
+        ActionEnum::Left{value}  => rotate(state, *value),
+
+        ActionEnum::Right{value} => rotate(state, -(*value)),
+
+        ActionEnum::Forward{value} => State2{ x:state.x + state.wx * value, 
+                                              y:state.y + state.wy * value,
+                                              wx:state.wx,
+                                              wy:state.wy},  
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<ActionEnum>;
+
+    fn parse(input: &str) -> Result<Vec<ActionEnum>> {
+        parse_lines(input, parse_action)
+    }
+
+    fn part1(actions: &Vec<ActionEnum>) -> Answer {
+        let end_point = actions.iter()
+            .fold(
+                State1{x:0.0,y:0.0,heading:0.0}, 
+                |state, action| update1(&state, action));
+
+        ((end_point.x.abs() + end_point.y.abs()).round() as i64).into()
+    }
+
+    fn part2(actions: &Vec<ActionEnum>) -> Answer {
+        let end_point = actions.iter()
+            .fold(
+                State2{x:0.0,y:0.0,wx:10.0,wy:1.0},
+                |state, action| update2(&state, action));
+
+        ((end_point.x.abs() + end_point.y.abs()).round() as i64).into()
+    }
+}
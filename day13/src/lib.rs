@@ -0,0 +1,105 @@
+#[macro_use] extern crate lazy_static;
+use aoc_common::{parse_number, Answer, Error, Result, Solution};
+use regex::Regex;
+
+fn parse_ids1<'a>(line: &'a str) -> impl Iterator<Item=u32> +'a {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"([\d]+)").unwrap();
+    }
+
+    RE.captures_iter(line).map(|x|x[1].parse::<u32>().unwrap())
+}
+
+fn parse_ids2<'a>(line: &'a str) -> impl Iterator<Item=(usize,u64)> +'a {
+
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"(?P<padding>(x,)*)(?P<value>[\d]+)").unwrap();
+    }
+
+    RE.captures_iter(line).map(|x|{
+        (x["padding"].split(',').count(), x["value"].parse::<u64>().unwrap())})
+}
+
+fn find_base((offset1, value1) : (u64,u64), (offset2, value2) : (u64,u64) ) -> (u64,u64){
+    let mut base = offset1;
+
+    loop{
+        base += value1;
+        if (base + offset2)%value2 == 0
+        {
+            break;
+        }
+    }
+
+    (base, value1*value2)
+}
+
+pub struct Notes {
+    earliest_departure: u32,
+    bus_ids: String,
+}
+
+fn check_bus_ids(line: &str) -> Result<()> {
+    let mut ids = line.split(',').filter(|id| *id != "x").peekable();
+    if ids.peek().is_none() {
+        return Err(Error::parse(line, line, "expected at least one bus in service"));
+    }
+
+    for id in ids {
+        if parse_number::<u32>(line, id)? == 0 {
+            return Err(Error::parse(line, id, "bus ids start at 1"));
+        }
+    }
+    Ok(())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Notes> {
+        let mut lines = input.lines();
+        let departure_line = lines.next().unwrap_or("");
+        let bus_line = lines.next()
+            .ok_or_else(|| Error::parse("", "", "expected a line of bus ids").offset_lines(1))?;
+
+        let earliest_departure = parse_number(departure_line, departure_line)?;
+        check_bus_ids(bus_line).map_err(|e| e.offset_lines(1))?;
+
+        Ok(Notes {
+            earliest_departure,
+            bus_ids: bus_line.to_string(),
+        })
+    }
+
+    fn part1(notes: &Notes) -> Answer {
+        let ids = parse_ids1(&notes.bus_ids);
+
+        let earliest_departure = notes.earliest_departure as u64;
+        let mut closest_departures : Vec<(u64, u64)> = ids.map(|x| x as u64).map(|x|{
+            (x, ((earliest_departure/x)+1) *x)
+        }).collect();
+
+        closest_departures.sort_by_key(|(_,x)| *x);
+        let (id, timestamp) = closest_departures[0];
+        ((timestamp- earliest_departure) * id).into()
+    }
+
+    fn part2(notes: &Notes) -> Answer {
+        let offsets_and_ids : Vec<(usize,u64)> = parse_ids2(&notes.bus_ids).collect();
+
+        let (_, base_id) : (usize,u64) = offsets_and_ids[0];
+
+        let mut cumulitive_offsets_and_ids = Vec::<(usize,u64)>::new();
+
+        let _ = offsets_and_ids[1..].iter().fold((0, &mut cumulitive_offsets_and_ids ), |(acc, vec), (offset, value)|{
+            vec.push((offset+acc,*value));
+            (offset+acc, vec)
+        });
+
+        let (final_offset, _) = cumulitive_offsets_and_ids.iter().fold((0, base_id), |base, (offset, id)| find_base(base, ((*offset) as u64,* id)));
+
+        final_offset.into()
+    }
+}
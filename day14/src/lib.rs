@@ -0,0 +1,223 @@
+#[macro_use] extern crate lazy_static;
+use aoc_common::{parse_lines, parse_number, Answer, Error, Result, Solution};
+use log::{debug, trace};
+use std::collections::HashMap;
+use regex::Regex;
+
+#[derive(Clone, Debug)]
+struct MaskInstruction1 {
+    and_mask : u64,
+    or_mask : u64,
+    #[allow(dead_code)]
+    mask_str: String,
+}
+
+impl MaskInstruction1 {
+    fn new(mask_str: &str) -> MaskInstruction1 {
+
+    let (and_mask, or_mask) = mask_str.chars().fold((0,0), |(and_mask, or_mask), c|{
+        match c {
+            '0' => ((and_mask<<1),     (or_mask<<1)),
+            '1' => ((and_mask<<1) + 1, (or_mask<<1) + 1),
+            'X' => ((and_mask<<1) + 1, (or_mask<<1)),
+            _ => panic!("Unkown Character!")
+        }
+    });
+
+    trace!("Mask:     {}", mask_str);
+    trace!("and_mask: {:036b}", and_mask);
+    trace!("or_mask:  {:036b}", or_mask);
+
+    MaskInstruction1{and_mask, or_mask, mask_str:mask_str.to_string()}
+    }
+
+    fn apply(&self, value: u64) -> u64 {
+        ( value & self.and_mask ) | self.or_mask
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct MemInstruction {
+    address : u64,
+    value : u64,
+}
+
+#[derive(Clone)]
+enum Instruction1 {
+    Mask(MaskInstruction1),
+    Mem(MemInstruction),
+}
+
+struct State1 {
+    current_mask : MaskInstruction1,
+    memory : HashMap<u64, u64>,
+}
+
+impl State1 {
+    fn new() -> State1 {
+        State1{
+            current_mask: MaskInstruction1::new("XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"),
+            memory : HashMap::new(),
+        }
+    }
+
+    fn execute(mut self, instruction: Instruction1) -> State1 {
+        match instruction {
+            Instruction1::Mask(mask) => {
+                debug!("Updated Mask: {}", mask.mask_str);
+                self.current_mask = mask;},
+            Instruction1::Mem(mem) => {
+                trace!("Wrote to Memory[{}]: {} masked with {} = {}", mem.address, mem.value, self.current_mask.mask_str, self.current_mask.apply(mem.value));
+                self.memory.insert(mem.address, self.current_mask.apply(mem.value)); },
+        };
+
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MaskInstruction2 {
+    or_mask : u64,
+    floatings : Vec<usize>,
+    #[allow(dead_code)]
+    mask_str: String,
+}
+
+impl MaskInstruction2 {
+    fn new(mask_str: &str) -> MaskInstruction2 {
+
+    let mut floatings = Vec::new();
+
+    let (or_mask, _) = mask_str.chars().fold((0,0), |(or_mask, index), c|{
+        match c {
+            '0' => ((or_mask<<1), index +1 ),
+            '1' => ((or_mask<<1) + 1, index +1),
+            'X' => { floatings.push(35-index);
+                   ((or_mask<<1), index +1 )},
+            _ => panic!("Unkown Character!")
+        }
+    });
+
+    MaskInstruction2{or_mask, floatings, mask_str:mask_str.to_string()}
+    }
+
+    fn apply(&self, value: u64) -> Vec<u64>{
+        let masked_value = value | self.or_mask;
+
+        self.floatings.iter()
+            .fold(vec![masked_value], |values, index| {
+                values.iter()
+                    .map(|x| x | (1<<index))
+                    .chain(
+                        values.iter()
+                        .map(|x| x & !(1<<index)))
+                        .collect()})
+    }
+}
+
+#[derive(Clone)]
+enum Instruction2 {
+    Mask(MaskInstruction2),
+    Mem(MemInstruction),
+}
+
+struct State2 {
+    current_mask : MaskInstruction2,
+    memory : HashMap<u64, u64>,
+}
+
+impl State2 {
+    fn new() -> State2 {
+        State2{
+            current_mask: MaskInstruction2::new("000000000000000000000000000000000000"),
+            memory : HashMap::new(),
+        }
+    }
+
+    fn execute(mut self, instruction: Instruction2) -> State2 {
+        match instruction {
+            Instruction2::Mask(mask) => {
+                debug!("Updated Mask: {}", mask.mask_str);
+                self.current_mask = mask;},
+            Instruction2::Mem(mem) => {
+                for addr in self.current_mask.apply(mem.address).iter() {
+                    trace!("Writing {} to {:036b}", mem.value, addr);
+                    self.memory.insert(*addr, mem.value);
+                }
+            },
+        };
+
+        self
+    }
+}
+
+enum Instruction {
+    Mask(String),
+    Mem(MemInstruction),
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(?P<instruction>mask|mem)(\[(?P<address>\d+)\])? = (?P<value>[0-9X]+)$").unwrap();
+    }
+
+    let cap = RE.captures(line)
+        .ok_or_else(|| Error::parse(line, line, "expected `mask = <mask>` or `mem[<address>] = <value>`"))?;
+    let value = cap.name("value").unwrap().as_str();
+
+    match (&cap["instruction"], cap.name("address")) {
+        ("mask", None) => match value.find(|c| c != '0' && c != '1' && c != 'X') {
+            Some(i) => Err(Error::parse(line, &value[i..], "expected `0`, `1` or `X`")),
+            None if value.len() != 36 => Err(Error::parse(line, value, "masks are 36 bits long")),
+            None => Ok(Instruction::Mask(value.to_string())),
+        },
+
+        ("mem", Some(address)) => Ok(Instruction::Mem(MemInstruction{ address:parse_number(line, address.as_str())?, 
+                                                                       value:parse_number(line, value)?})),
+
+        _ => Err(Error::parse(line, line, "only `mem` takes an address")),
+    }
+}
+
+/// The program decoded for both chip versions: masking values, and masking addresses.
+pub struct Program {
+    instructions1: Vec<Instruction1>,
+    instructions2: Vec<Instruction2>,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Program> {
+        let instructions = parse_lines(input, parse_instruction)?;
+
+        Ok(Program {
+            instructions1: instructions.iter().map(|instr| match instr {
+                Instruction::Mask(mask) => Instruction1::Mask(MaskInstruction1::new(mask)),
+                Instruction::Mem(mem) => Instruction1::Mem(*mem),
+            }).collect(),
+            instructions2: instructions.iter().map(|instr| match instr {
+                Instruction::Mask(mask) => Instruction2::Mask(MaskInstruction2::new(mask)),
+                Instruction::Mem(mem) => Instruction2::Mem(*mem),
+            }).collect(),
+        })
+    }
+
+    fn part1(program: &Program) -> Answer {
+        let instructions = program.instructions1.iter().cloned();
+
+        let final_state = instructions.fold(State1::new(), |state, instr|state.execute(instr));
+
+        final_state.memory.values().sum::<u64>().into()
+    }
+
+    fn part2(program: &Program) -> Answer {
+        let instructions = program.instructions2.iter().cloned();
+
+        let final_state = instructions.fold(State2::new(), |state, instr|state.execute(instr));
+
+        final_state.memory.values().sum::<u64>().into()
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse_number, Answer, Error, Result, Solution};
+use log::trace;
+
+fn play_one_round( tracker: &mut HashMap<u32, u32>, turn_counter: u32, last_value: u32) -> u32 {
+    trace!("Turn: {} -> {}", turn_counter, last_value);
+    match tracker.insert(last_value, turn_counter) {
+        Some(turn) => turn_counter - turn,
+        None => 0
+    }
+}
+
+fn parse_initial_values(input: &str) -> Result<Vec<u32>> {
+    let line = input.lines().next().unwrap_or("").trim();
+    if line.is_empty() {
+        return Err(Error::parse(line, line, "expected comma-separated starting numbers"));
+    }
+
+    line.split(',').map(|x| parse_number(line, x)).collect()
+}
+
+fn play_game(initial_values: &[u32], turns: u32) -> u32 {
+    let mut tracker : HashMap<u32, u32> = HashMap::new();
+    let mut turn_counter = 0;
+
+    for value in initial_values.iter() {
+        turn_counter +=1;
+        tracker.insert(*value, turn_counter);
+    }
+
+    (turn_counter..turns).fold(*initial_values.last().unwrap(), |last_value, turn| play_one_round(&mut tracker, turn, last_value))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Vec<u32>> {
+        parse_initial_values(input)
+    }
+
+    fn part1(initial_values: &Vec<u32>) -> Answer {
+        play_game(initial_values, 2020).into()
+    }
+
+    fn part2(initial_values: &Vec<u32>) -> Answer {
+        play_game(initial_values, 30000000).into()
+    }
+}
@@ -0,0 +1,204 @@
+#[macro_use] extern crate lazy_static;
+use aoc_common::{parse_number, records, Answer, Error, Record, Result, Solution};
+use log::{debug, trace};
+use regex::Regex;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Field {
+    name: String,
+    range1: std::ops::Range<u32>,
+    range2: std::ops::Range<u32>,
+}
+
+impl Field {
+    fn is_valid(&self, value:u32) -> bool {
+        self.range1.contains(&value) || self.range2.contains(&value)
+    }
+}
+
+fn parse_field(line: &str) -> Result<Field> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^([a-z ]+): (\d+)-(\d+) or (\d+)-(\d+)$").unwrap();
+    }
+
+    let cap = RE.captures(line)
+        .ok_or_else(|| Error::parse(line, line, "expected `<name>: <min>-<max> or <min>-<max>`"))?;
+    let bound = |i| parse_number::<u32>(line, cap.get(i).unwrap().as_str());
+
+    let range1_start = bound(2)?;
+    let range1_end   = bound(3)?.saturating_add(1);
+    let range2_start = bound(4)?;
+    let range2_end   = bound(5)?.saturating_add(1);
+
+    Ok(Field{
+        name:cap[1].to_string(),
+        range1: range1_start..range1_end,
+        range2: range2_start..range2_end,
+    })
+}
+
+fn parse_ticket(line: &str) -> Result<Vec<u32>> {
+    line.split(',').map(|value| parse_number(line, value)).collect()
+}
+
+fn sum_invalid_values(fields: &[Field], tickets: &[Vec<u32>]) -> u32 {
+
+    let mut invalid_numbers : Vec<u32> = Vec::new();
+
+    for ticket in tickets.iter() {
+        for value in ticket.iter() {
+            if !fields.iter().any(|field|field.is_valid(*value)) {
+                invalid_numbers.push(*value);
+            }
+        }
+    }
+
+    invalid_numbers.iter().sum::<u32>()
+}
+
+fn value_is_possibly_valid(fields: &[Field], value:u32) -> bool {
+    fields.iter().any( |field| field.is_valid(value))
+}
+
+fn ticket_is_valid(fields: &[Field], ticket: &[u32] ) -> bool {
+    ticket.iter().all(|value| value_is_possibly_valid(fields, *value))
+}
+
+fn is_one_to_one(field_map: &[Vec<Field>]) -> bool {
+    field_map.iter().all(|fields| fields.len() == 1)
+}
+
+fn count_instances(field_map: &[Vec<Field>], field: &Field) -> u32 {
+    field_map.iter().map(|fields|{
+        if fields.contains(field) {
+            1
+        } else {
+            0
+        }
+    }).sum()
+}
+
+/// The product of the `departure` values on `my_ticket`, or `None` if the tickets don't narrow
+/// each position down to one field.
+fn departure_product(fields: &[Field], my_ticket: &[u32], tickets: &[Vec<u32>]) -> Option<u64> {
+    let valid_tickets : Vec<Vec<u32>> = tickets.iter().filter(|ticket|ticket_is_valid(fields, ticket)).cloned().collect();
+
+    let mut field_map : Vec<Vec<Field>> = (0..fields.len()).map(|_| fields.to_vec()).collect();
+
+    for ticket in valid_tickets.iter() {
+        for (i, value) in ticket.iter().enumerate() {
+            field_map[i] = field_map[i].iter().filter(|field|field.is_valid(*value)).cloned().collect();
+        }
+    }
+
+    while !is_one_to_one(&field_map) {
+        debug!("Attempting to Reduce Field Map");
+        let before = field_map.clone();
+        let fixed_fields : Vec<Field>= fields.iter().filter(|field|count_instances(&field_map, field)==1).cloned().collect();
+
+        for field in fixed_fields {
+            for candidates in field_map.iter_mut() {
+                if candidates.contains(&field) {
+                    *candidates = vec![field.clone()];
+                }
+            }
+        }
+
+        for i in 0..field_map.len() {
+            if field_map[i].len() == 1 {
+                let pinned_field = field_map[i][0].clone();
+                for (j, candidates) in field_map.iter_mut().enumerate() {
+                    if j != i {
+                        candidates.retain(|x| *x != pinned_field);
+                    }
+                }
+            }
+        } 
+
+        for (i, candidates) in field_map.iter().enumerate() {
+            trace!("  Position {} could be:", i);
+            for field in candidates.iter() {
+                trace!("    {}", field.name);
+            }
+        }
+
+        if field_map == before {
+            debug!("The tickets don't narrow every position down to one field");
+            return None;
+        }
+    }
+
+    let final_map : Vec<(usize,Field)> = field_map.iter().enumerate().map(|(i,x)|(i, x[0].clone())).collect();
+    let dest_fields : Vec<(usize,Field)> = final_map.iter().filter(|(_,x)| x.name.starts_with("departure")).cloned().collect();
+
+    let dest_vales : Vec<u64> = dest_fields.iter().map(|(i,_)|my_ticket[*i] as u64).collect();
+
+    debug!("Departure values: {:?}", dest_vales);
+    dest_vales.iter().try_fold(1u64, |product, x| product.checked_mul(*x))
+}
+
+pub struct Notes {
+    fields: Vec<Field>,
+    my_ticket: Vec<u32>,
+    tickets: Vec<Vec<u32>>,
+}
+
+/// Parses the lines of `section` below its `header` as tickets, each with a value per field.
+fn parse_tickets(section: &Record, header: &str, fields: &[Field]) -> Result<Vec<Vec<u32>>> {
+    section.parse_line(0, |line| {
+        if line != header {
+            return Err(Error::parse(line, line, format!("expected `{}`", header)));
+        }
+        Ok(())
+    })?;
+
+    (1..section.lines.len()).map(|index| section.parse_line(index, |line| {
+        let ticket = parse_ticket(line)?;
+        if ticket.len() != fields.len() {
+            let message = format!("expected {} values, one per field, found {}", fields.len(), ticket.len());
+            return Err(Error::parse(line, line, message));
+        }
+        Ok(ticket)
+    })).collect()
+}
+
+/// Parses the notes as the puzzle gives them: the fields, then `your ticket:` and `nearby tickets:`
+/// sections, separated by blank lines.
+fn parse_notes(input: &str) -> Result<Notes> {
+    let mut sections = records(input.as_bytes());
+    let mut next_section = |header: &str| match sections.next() {
+        Some(section) => Ok(section?),
+        None => Err(Error::parse("", "", format!("expected a `{}` section", header)).offset_lines(input.lines().count())),
+    };
+
+    let fields = next_section("<name>: <min>-<max> or <min>-<max>")?.parse_lines(parse_field)?;
+
+    let section = next_section("your ticket:")?;
+    let mut my_tickets = parse_tickets(&section, "your ticket:", &fields)?;
+    if my_tickets.len() != 1 {
+        let message = format!("expected one ticket, found {}", my_tickets.len());
+        return section.parse_line(0, |line| Err(Error::parse(line, line, message)));
+    }
+
+    let tickets = parse_tickets(&next_section("nearby tickets:")?, "nearby tickets:", &fields)?;
+
+    Ok(Notes { fields, my_ticket: my_tickets.remove(0), tickets })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Notes> {
+        parse_notes(input)
+    }
+
+    fn part1(notes: &Notes) -> Answer {
+        sum_invalid_values(&notes.fields, &notes.tickets).into()
+    }
+
+    fn part2(notes: &Notes) -> Answer {
+        departure_product(&notes.fields, &notes.my_ticket, &notes.tickets).map_or(Answer::Unsolved, |x| x.into())
+    }
+}
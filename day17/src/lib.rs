@@ -0,0 +1,257 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use itertools::Itertools;
+use aoc_common::{Answer, Frame, Grid, Layer, Result, Solution};
+use log::{debug, trace};
+
+
+struct ConwayCube3D {
+    space: HashMap<(i32,i32,i32), bool>
+}
+
+impl ConwayCube3D {
+
+    fn new() -> ConwayCube3D {
+        ConwayCube3D{space: HashMap::new()}
+    }
+
+    fn is_active(&self, pos:(i32, i32, i32)) -> bool {
+        match self.space.get(&pos) {
+            None => false,
+            Some(x) => *x,
+        }
+    }
+
+    fn get_neighbor_positions(&self, pos:(i32, i32, i32) ) -> impl Iterator<Item=(i32,i32,i32)> {
+        let (x,y,z) = pos;
+        Itertools::multi_cartesian_product([x-1..x+2, y-1..y+2, z-1..z+2].iter().cloned())
+            .map(|arr| (arr[0], arr[1], arr[2]) )
+            .filter(move |neighbor| *neighbor != pos)
+    }
+
+    fn spawn_neighbors(&mut self, pos:(i32, i32, i32)) {
+        for neighbor_pos in self.get_neighbor_positions(pos) {
+            self.space.entry(neighbor_pos).or_insert(false);
+        }
+    }
+
+    fn set_state(&mut self, pos:(i32, i32, i32), active:bool) {
+        self.space.insert(pos, active);
+
+        if active {
+            self.spawn_neighbors(pos);
+        }
+    }
+
+    fn all_defined_cells(&self) -> impl Iterator<Item=(&(i32,i32,i32), &bool)> {
+        self.space.iter()
+    }
+
+    fn count_active_neighbors(&self, pos:(i32, i32, i32) ) -> i32 {
+        self.get_neighbor_positions(pos)
+            .map(|neighbor_pos| {
+                self.is_active(neighbor_pos)})
+            .filter(|active| *active).count() as i32
+    }
+}
+
+fn run_one_cycle_3d(cube: ConwayCube3D ) -> ConwayCube3D {
+    let mut new_cube = ConwayCube3D::new();
+    let all_cells : Vec<((i32,i32,i32), bool)> = cube.all_defined_cells().map(|(pos, active)| (*pos, *active)).collect();
+    
+    for (pos, active) in all_cells {
+        let active_neighbors = cube.count_active_neighbors(pos);
+
+        trace!("Cell {:?} is {} has {} active neighbors", pos, active, active_neighbors );
+
+        if active_neighbors == 3 || (active && active_neighbors == 2) {
+            new_cube.set_state(pos, true);
+        }
+        else {
+            new_cube.set_state(pos, false);
+        }     
+    }
+    new_cube
+}
+
+fn make_cube_3d(slice: &Grid<bool>) -> ConwayCube3D {
+    let mut cube = ConwayCube3D::new();
+
+    for (y, row) in slice.rows().enumerate() {
+        for (x, active) in row.iter().enumerate() {
+            if *active {
+                cube.set_state((x as i32,y as i32, 0), true);
+            }
+        }
+    }
+
+    cube
+}
+
+struct ConwayCube4D {
+    space: HashMap<(i32,i32,i32,i32), bool>
+}
+
+impl ConwayCube4D {
+
+    fn new() -> ConwayCube4D {
+        ConwayCube4D{space: HashMap::new()}
+    }
+
+    fn is_active(&self, pos:(i32, i32, i32, i32)) -> bool {
+        match self.space.get(&pos) {
+            None => false,
+            Some(x) => *x,
+        }
+    }
+
+    fn get_neighbor_positions(&self, pos:(i32, i32, i32, i32) ) -> impl Iterator<Item=(i32,i32,i32,i32)> {
+        let (x,y,z,w) = pos;
+        Itertools::multi_cartesian_product([x-1..x+2, y-1..y+2, z-1..z+2, w-1..w+2].iter().cloned())
+            .map(|arr| (arr[0], arr[1], arr[2], arr[3]) )
+            .filter(move |neighbor| *neighbor != pos)
+    }
+
+    fn spawn_neighbors(&mut self, pos:(i32, i32, i32, i32)) {
+        for neighbor_pos in self.get_neighbor_positions(pos) {
+            self.space.entry(neighbor_pos).or_insert(false);
+        }
+    }
+
+    fn set_state(&mut self, pos:(i32, i32, i32, i32), active:bool) {
+        self.space.insert(pos, active);
+
+        if active {
+            self.spawn_neighbors(pos);
+        }
+    }
+
+    fn all_defined_cells(&self) -> impl Iterator<Item=(&(i32,i32,i32,i32), &bool)> {
+        self.space.iter()
+    }
+
+    fn count_active_neighbors(&self, pos:(i32, i32, i32, i32) ) -> i32 {
+        self.get_neighbor_positions(pos)
+            .map(|neighbor_pos| {
+                self.is_active(neighbor_pos)})
+            .filter(|active| *active).count() as i32
+    }
+}
+
+fn run_one_cycle_4d(cube: ConwayCube4D ) -> ConwayCube4D {
+    let mut new_cube = ConwayCube4D::new();
+    let all_cells : Vec<((i32,i32,i32,i32), bool)> = cube.all_defined_cells().map(|(pos, active)| (*pos, *active)).collect();
+    
+    for (pos, active) in all_cells {
+        let active_neighbors = cube.count_active_neighbors(pos);
+
+        trace!("Cell {:?} is {} has {} active neighbors", pos, active, active_neighbors );
+
+        if active_neighbors == 3 || (active && active_neighbors == 2) {
+            new_cube.set_state(pos, true);
+        }
+        else {
+            new_cube.set_state(pos, false);
+        }     
+    }
+    new_cube
+}
+
+fn make_cube_4d(slice: &Grid<bool>) -> ConwayCube4D {
+    let mut cube = ConwayCube4D::new();
+
+    for (y, row) in slice.rows().enumerate() {
+        for (x, active) in row.iter().enumerate() {
+            if *active {
+                cube.set_state((x as i32,y as i32, 0, 0), true);
+            }
+        }
+    }
+
+    cube
+}
+
+/// Draws the active cubes as a layer per (z, w), each covering the x and y range of every active cube.
+fn cube_frame(label: String, active: Vec<(i32,i32,i32,i32)>) -> Frame {
+    let min_x = active.iter().map(|pos| pos.0).min().unwrap_or(0);
+    let max_x = active.iter().map(|pos| pos.0).max().unwrap_or(-1);
+    let min_y = active.iter().map(|pos| pos.1).min().unwrap_or(0);
+    let max_y = active.iter().map(|pos| pos.1).max().unwrap_or(-1);
+
+    let layers : BTreeSet<(i32,i32)> = active.iter().map(|pos| (pos.2, pos.3)).collect();
+    let active : HashSet<(i32,i32,i32,i32)> = active.into_iter().collect();
+
+    let layers = layers.into_iter().map(|(z, w)| {
+        let grid = Grid::from_fn((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize, |x, y| {
+            if active.contains(&(min_x + x as i32, min_y + y as i32, z, w)) { '#' } else { '.' }
+        });
+        Layer { z, w, grid }
+    }).collect();
+
+    Frame { label, layers }
+}
+
+fn frame_3d(cycle: usize, cube: &ConwayCube3D) -> Frame {
+    let active : Vec<(i32,i32,i32,i32)> = cube.all_defined_cells().filter(|(_, active)| **active).map(|((x,y,z), _)| (*x,*y,*z,0)).collect();
+    cube_frame(format!("Cycle {}: {} active", cycle, active.len()), active)
+}
+
+fn frame_4d(cycle: usize, cube: &ConwayCube4D) -> Frame {
+    let active : Vec<(i32,i32,i32,i32)> = cube.all_defined_cells().filter(|(_, active)| **active).map(|(pos, _)| *pos).collect();
+    cube_frame(format!("Cycle {}: {} active", cycle, active.len()), active)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Grid<bool>> {
+        input.parse()
+    }
+
+    fn part1(slice: &Grid<bool>) -> Answer {
+        let mut cube = make_cube_3d(slice);
+
+        for i in 1..7 {
+            cube = run_one_cycle_3d(cube);
+            debug!("Generation {}: {}", i, cube.all_defined_cells().filter(|(_, active)| **active).count());
+        }
+
+        cube.all_defined_cells().filter(|(_, active)| **active).count().into()
+    }
+
+    fn part2(slice: &Grid<bool>) -> Answer {
+        let mut cube = make_cube_4d(slice);
+
+        for i in 1..7 {
+            cube = run_one_cycle_4d(cube);
+            debug!("Generation {}: {}", i, cube.all_defined_cells().filter(|(_, active)| **active).count());
+        }
+
+        cube.all_defined_cells().filter(|(_, active)| **active).count().into()
+    }
+
+    fn frames(slice: &Grid<bool>, part: u8) -> Vec<Frame> {
+        match part {
+            1 => {
+                let mut cube = make_cube_3d(slice);
+                let mut frames = vec![frame_3d(0, &cube)];
+                for i in 1..7 {
+                    cube = run_one_cycle_3d(cube);
+                    frames.push(frame_3d(i, &cube));
+                }
+                frames
+            }
+            2 => {
+                let mut cube = make_cube_4d(slice);
+                let mut frames = vec![frame_4d(0, &cube)];
+                for i in 1..7 {
+                    cube = run_one_cycle_4d(cube);
+                    frames.push(frame_4d(i, &cube));
+                }
+                frames
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use aoc_common::{parse_lines, Answer, Error, Result, Solution};
+use log::trace;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Symbol {
+    Number{value:u64},
+    Addition,
+    Multiplication,
+    OpenParentheses,
+    CloseParentheses,
+}
+
+
+fn p1_next_value<T>(symbols: &mut T) -> u64 
+where T: Iterator< Item=Symbol > {
+    match symbols.next() {
+        None => panic!("Missing Right-Hand Value for Operator!"),
+        Some(Symbol::OpenParentheses) => p1_evaluate(p1_next_value(symbols), symbols),
+        Some(Symbol::Number{value}) => value,
+        Some(x) => panic!("Unexpected Symbol! Needed an Operator, got {:?}", x)
+    }
+}
+
+fn p1_evaluate<T>( inital_value: u64, symbols: &mut T) -> u64 
+where T: Iterator< Item=Symbol > {
+
+    match symbols.next() {
+        None => inital_value,
+
+        Some(Symbol::Addition) => {
+            let next = p1_next_value(symbols);
+            trace!("{} + {} = {}", inital_value, next, inital_value + next);
+            p1_evaluate(inital_value + next, symbols)},
+
+        Some(Symbol::Multiplication) => {
+            let next = p1_next_value(symbols);
+            trace!("{} * {} = {}", inital_value, next, inital_value * next);
+            p1_evaluate(inital_value * next, symbols)},
+
+        Some(Symbol::CloseParentheses) => inital_value,
+        Some(x) => panic!("Unexpected Symbol! Needed an Operator, got {:?}", x)
+    }
+}
+
+fn p2_next_value<T>(symbols: &mut T) -> u64 
+where T: Iterator< Item=Symbol > {
+    match symbols.next() {
+        None => panic!("Missing Right-Hand Value for Operator!"),
+        Some(Symbol::OpenParentheses) => p2_evaluate(p2_next_value(symbols), symbols),
+        Some(Symbol::Number{value}) => value,
+        Some(x) => panic!("Unexpected Symbol! Needed an Operator, got {:?}", x)
+    }
+}
+
+fn p2_evaluate<T>( inital_value: u64, symbols: &mut T) -> u64 
+where T: Iterator< Item=Symbol > {
+
+    match symbols.next() {
+        None => inital_value,
+
+        Some(Symbol::Addition) => {
+            let next = p2_next_value(symbols);
+            trace!("{} + {} = {}", inital_value, next, inital_value + next);
+            p2_evaluate(inital_value + next, symbols)},
+
+        Some(Symbol::Multiplication) => {
+            let next = p2_next_value(symbols);
+            trace!("{} * {} = {}", inital_value, next, inital_value * next);
+            inital_value * p2_evaluate(next, symbols)},
+
+        Some(Symbol::CloseParentheses) => inital_value,
+        Some(x) => panic!("Unexpected Symbol! Needed an Operator, got {:?}", x)
+    }
+}
+
+fn to_symbol(c: char) -> Option<Symbol> {
+    match c {
+        '+' => Some(Symbol::Addition),
+        '*' => Some(Symbol::Multiplication),
+        '(' => Some(Symbol::OpenParentheses),
+        ')' => Some(Symbol::CloseParentheses),
+        number @ '0'..='9' => Some(Symbol::Number{value:number.to_digit(10).unwrap() as u64}),
+        _ => None,
+    }
+}
+
+/// Splits a line into symbols, checking that values and operators alternate and parentheses balance.
+fn parse_line(line: &str) -> Result<Vec<Symbol>> {
+    let mut symbols = Vec::new();
+    let mut expect_value = true;
+    let mut depth = 0;
+
+    for (i, c) in line.char_indices().filter(|(_, c)| *c != ' ') {
+        let symbol = to_symbol(c)
+            .ok_or_else(|| Error::parse(line, &line[i..], format!("Unknown Symbol `{}`", c)))?;
+
+        match (expect_value, symbol) {
+            (true, Symbol::Number{..}) => expect_value = false,
+            (true, Symbol::OpenParentheses) => depth += 1,
+            (false, Symbol::Addition) | (false, Symbol::Multiplication) => expect_value = true,
+            (false, Symbol::CloseParentheses) if depth > 0 => depth -= 1,
+            (true, _) => return Err(Error::parse(line, &line[i..], "expected a number or `(`")),
+            (false, _) => return Err(Error::parse(line, &line[i..], "expected an operator or `)`")),
+        }
+        symbols.push(symbol);
+    }
+
+    if expect_value {
+        Err(Error::parse(line, &line[line.len()..], "Missing Right-Hand Value for Operator!"))
+    } else if depth > 0 {
+        Err(Error::parse(line, &line[line.len()..], "expected `)`"))
+    } else {
+        Ok(symbols)
+    }
+}
+
+fn p1_evaluate_line(symbols: &[Symbol]) -> u64 {
+    let mut iter = symbols.iter().copied();
+
+    p1_evaluate(p1_next_value(&mut iter), &mut iter)
+}
+
+fn p2_evaluate_line(symbols: &[Symbol]) -> u64 {
+    let mut iter = symbols.iter().copied();
+
+    p2_evaluate(p2_next_value(&mut iter), &mut iter)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Vec<Symbol>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Symbol>>> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part1(lines: &Vec<Vec<Symbol>>) -> Answer {
+        let total : u64 = lines.iter().map(|x| p1_evaluate_line(x)).sum();
+        total.into()
+    }
+
+    fn part2(lines: &Vec<Vec<Symbol>>) -> Answer {
+        let total : u64 = lines.iter().map(|x| p2_evaluate_line(x)).sum();
+        total.into()
+    }
+}
+
+#[test]
+fn p1_example_1 () {
+    assert_eq!(p1_evaluate_line(&parse_line("1 + 2 * 3 + 4 * 5 + 6").unwrap()), 71);
+}
+
+#[test]
+fn p1_example_2 () {
+    assert_eq!(p1_evaluate_line(&parse_line("1 + (2 * 3) + (4 * (5 + 6))").unwrap()), 51);
+}
+
+#[test]
+fn p1_example_3 () {
+    assert_eq!(p1_evaluate_line(&parse_line("2 * 3 + (4 * 5)").unwrap()), 26);
+}
+
+#[test]
+fn p1_example_4 () {
+    assert_eq!(p1_evaluate_line(&parse_line("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap()), 437);
+}
+
+#[test]
+fn p1_example_5 () {
+    assert_eq!(p1_evaluate_line(&parse_line("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap()), 12240);
+}
+
+#[test]
+fn p1_example_6 () {
+    assert_eq!(p1_evaluate_line(&parse_line("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap()), 13632);
+}
+
+#[test]
+fn p2_example_1 () {
+    assert_eq!(p2_evaluate_line(&parse_line("1 + 2 * 3 + 4 * 5 + 6").unwrap()), 231);
+}
+
+#[test]
+fn p2_example_2 () {
+    assert_eq!(p2_evaluate_line(&parse_line("1 + (2 * 3) + (4 * (5 + 6))").unwrap()), 51);
+}
+
+#[test]
+fn p2_example_3 () {
+    assert_eq!(p2_evaluate_line(&parse_line("2 * 3 + (4 * 5)").unwrap()), 46);
+}
+
+#[test]
+fn p2_example_4 () {
+    assert_eq!(p2_evaluate_line(&parse_line("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap()), 1445);
+}
+
+#[test]
+fn p2_example_5 () {
+    assert_eq!(p2_evaluate_line(&parse_line("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap()), 669060);
+}
+
+#[test]
+fn p2_example_6 () {
+    assert_eq!(p2_evaluate_line(&parse_line("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap()), 23340);
+}
+
+#[test]
+fn rejects_malformed_expressions () {
+    assert!(parse_line("1 + (2 * 3").is_err());
+    assert!(parse_line("1 + * 3").is_err());
+    assert!(parse_line("1 + 2)").is_err());
+    assert!(parse_line("1 - 2").is_err());
+    assert!(parse_line("1 +").is_err());
+}
@@ -0,0 +1,265 @@
+use aoc_common::{parse_number, Answer, Error, Result, Solution};
+use log::{debug, trace};
+use std::collections::HashMap;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum RuleType {
+    CharRule{c:char},
+    MetaRule{id_sequences:Vec<Vec<u32>>}
+}
+
+fn parse_rule(line: &str, rule: &str) -> Result<RuleType> {
+    let chars : Vec<char> = rule.chars().collect();
+
+    if let ['"', c, '"'] = chars[..] {
+        return Ok(RuleType::CharRule{c});
+    }
+
+    let id_sequences = rule.split('|')
+        .map(|x|{
+            let ids : Vec<&str> = x.split_whitespace().collect();
+            if ids.is_empty() {
+                return Err(Error::parse(line, x, "expected rule ids, or a quoted character"));
+            }
+            ids.iter().map(|y| parse_number::<u32>(line, y)).collect()})
+        .collect::<Result<Vec<Vec<u32>>>>()?;
+
+    Ok(RuleType::MetaRule{id_sequences})
+}
+
+fn parse_rule_line(line: &str, rules: &mut HashMap<u32, RuleType>) -> Result<()> {
+
+    let (id, rule) = line.split_once(": ")
+        .ok_or_else(|| Error::parse(line, line, "expected `<id>: <rule>`"))?;
+
+    rules.insert(parse_number(line, id)?, parse_rule(line, rule)?);
+    Ok(())
+}
+
+/// Finds a rule id that `line` refers to, but that no rule defines.
+fn check_rule_references(line: &str, rules: &HashMap<u32, RuleType>) -> Result<()> {
+    let (_, rule) = line.split_once(": ").unwrap();
+
+    if let Ok(RuleType::MetaRule{..}) = parse_rule(line, rule) {
+        for id in rule.split([' ', '|']).filter(|x| !x.is_empty()) {
+            if !rules.contains_key(&parse_number(line, id)?) {
+                return Err(Error::parse(line, id, format!("rule {} is not defined", id)));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn evaluate_meta_rule_sequence(ids: &[u32], rules: &HashMap<u32, RuleType>, chars:&[char]) -> Option<usize> {
+    if ids.len() > chars.len() {
+        None
+    } else {
+        let mut index = 0;
+
+        for id in ids {
+            if let Some(consumed) = evaluate_rule(*id, rules, &chars[index..]) {
+                index += consumed;
+            } else {
+                return None;
+            }
+        }
+        Some(index)
+    }
+}
+
+fn hungry_match(id:u32, rules: &HashMap<u32, RuleType>, chars:&[char]) -> Option<usize> {
+    let mut index = 0;
+    while let Some(consumed) = evaluate_rule(id, rules, &chars[index..]) {
+        index += consumed;
+    }
+    trace!("Hungry Matching Rule {} consumed {} characters", id, index);
+    if index > 0 {
+        Some(index)
+    }
+    else{
+        None
+    }
+}
+
+
+// With the looping rules "8: 42 | 42 8" and "11: 42 31 | 42 11 31", rule 0 ("8 11")
+// becomes: one or more 42s followed by fewer, but at least one, 31s.
+fn evaluate_looping_rule_0(rules: &HashMap<u32, RuleType>, chars:&[char]) -> Option<usize> {
+    if let Some(consumed_42) = hungry_match( 42, rules, chars ) {
+        if let Some(consumed_31) = hungry_match( 31, rules, &chars[consumed_42..] ) {
+            if consumed_42 > consumed_31 && consumed_42 >= 2 && consumed_31 >= 1{
+                return Some(consumed_42 + consumed_31);
+            } 
+        }
+    }
+    None
+}
+
+fn evaluate_rule(id:u32, rules: &HashMap<u32, RuleType>, chars:&[char]) -> Option<usize> {
+    trace!("Applying {} {:?} against {:?}", id, rules[&id], chars);
+
+    match &rules[&id] {
+        RuleType::CharRule{c} => {
+            if chars.first() == Some(c) {
+                Some(1)}
+            else{
+                None
+            }},
+
+        RuleType::MetaRule{id_sequences} => {
+            for id_sequence in id_sequences {
+                if let Some(consumed) = evaluate_meta_rule_sequence(id_sequence, rules, chars) {
+                    return Some(consumed)
+                }
+            }
+            None
+        }
+    }
+}
+
+fn make_rules_dict<S: AsRef<str>>(lines:&[S]) -> Result<HashMap::<u32, RuleType>> {
+    let mut rule_dict = HashMap::<u32, RuleType>::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        parse_rule_line(line.as_ref(), &mut rule_dict).map_err(|e| e.offset_lines(i))?;
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        check_rule_references(line.as_ref(), &rule_dict).map_err(|e| e.offset_lines(i))?;
+    }
+
+    Ok(rule_dict)
+}
+
+fn check_perfect_match(id:u32, rule_dict: &HashMap<u32, RuleType>, message: &str) -> bool {
+    let chars : Vec<char> = message.chars().collect();
+    is_perfect_match(evaluate_rule(id, rule_dict, &chars), &chars)
+}
+
+fn check_perfect_looping_match(rule_dict: &HashMap<u32, RuleType>, message: &str) -> bool {
+    let chars : Vec<char> = message.chars().collect();
+    is_perfect_match(evaluate_looping_rule_0(rule_dict, &chars), &chars)
+}
+
+fn is_perfect_match(result: Option<usize>, chars: &[char]) -> bool {
+
+    if let Some(consumed) = result {
+        if consumed == chars.len() {
+            debug!("Match! {:?}", chars);
+            true
+        } else {
+            debug!("Incomplete Match {:?}", chars);
+            false
+        }
+    } else {
+        debug!("No Match {:?}", chars);
+        false
+    }
+}
+
+
+pub struct Puzzle {
+    rule_dict: HashMap<u32, RuleType>,
+    messages: Vec<String>,
+}
+
+fn parse_puzzle(input: &str) -> Result<Puzzle> {
+    let mut lines = input.lines();
+    let rule_lines : Vec<&str> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+    let messages = lines.filter(|line| !line.is_empty()).map(|line| line.to_string()).collect();
+
+    Ok(Puzzle { rule_dict: make_rules_dict(&rule_lines)?, messages })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Puzzle> {
+        parse_puzzle(input)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Answer {
+        puzzle.messages.iter().filter(|x| check_perfect_match(0, &puzzle.rule_dict, x)).count().into()
+    }
+
+    fn part2(puzzle: &Puzzle) -> Answer {
+        puzzle.messages.iter().filter(|x| check_perfect_looping_match(&puzzle.rule_dict, x)).count().into()
+    }
+}
+
+#[test]
+fn p1_example_1 () {
+    let rules = vec![
+        "0: 1 2".to_string(),
+        "1: \"a\"".to_string(),
+        "2: 1 3 | 3 1".to_string(),
+        "3: \"b\"".to_string()];
+
+    let rule_dict = make_rules_dict(&rules).unwrap();
+
+    assert_eq!(evaluate_rule(0, &rule_dict, &['a','a','b']), Some(3));
+    assert_eq!(evaluate_rule(0, &rule_dict, &['a','b','a']), Some(3));
+    assert_eq!(evaluate_rule(0, &rule_dict, &['a','a','a']), None);
+    assert_eq!(evaluate_rule(0, &rule_dict, &['a','b']), None);
+    assert_eq!(evaluate_rule(0, &rule_dict, &['a','b','a','b']), Some(3));
+}
+
+#[test]
+fn p1_example_2 () {
+    let rules = vec![
+        "0: 4 1 5".to_string(),
+        "1: 2 3 | 3 2".to_string(),
+        "2: 4 4 | 5 5".to_string(),
+        "3: 4 5 | 5 4".to_string(),
+        "4: \"a\"".to_string(),
+        "5: \"b\"".to_string()];
+
+    let rule_dict = make_rules_dict(&rules).unwrap();
+
+    assert_eq!(evaluate_rule(0, &rule_dict, &['a','a','a','a','b','b']), Some(6));
+    assert_eq!(evaluate_rule(0, &rule_dict, &['a','b','a','b','b','b']), Some(6));
+    assert_eq!(evaluate_rule(0, &rule_dict, &['a','a','a','a','b','b','b']), Some(6));
+    assert_eq!(evaluate_rule(0, &rule_dict, &['b','a','b','a','b','a']), None);
+}
+
+#[test]
+fn p2_example_1 () {
+    let rules = vec![
+        "42: 9 14 | 10 1".to_string(),
+        "9: 14 27 | 1 26".to_string(),
+        "10: 23 14 | 28 1".to_string(),
+        "1: \"a\"".to_string(),
+        "11: 42 31".to_string(),
+        "5: 1 14 | 15 1".to_string(),
+        "19: 14 1 | 14 14".to_string(),
+        "12: 24 14 | 19 1".to_string(),
+        "16: 15 1 | 14 14".to_string(),
+        "31: 14 17 | 1 13".to_string(),
+        "6: 14 14 | 1 14".to_string(),
+        "2: 1 24 | 14 4".to_string(),
+        "0: 8 11".to_string(),
+        "13: 14 3 | 1 12".to_string(),
+        "15: 1 | 14".to_string(),
+        "17: 14 2 | 1 7".to_string(),
+        "23: 25 1 | 22 14".to_string(),
+        "28: 16 1".to_string(),
+        "4: 1 1".to_string(),
+        "20: 14 14 | 1 15".to_string(),
+        "3: 5 14 | 16 1".to_string(),
+        "27: 1 6 | 14 18".to_string(),
+        "14: \"b\"".to_string(),
+        "21: 14 1 | 1 14".to_string(),
+        "25: 1 1 | 1 14".to_string(),
+        "22: 14 14".to_string(),
+        "8: 42".to_string(),
+        "26: 14 22 | 1 20".to_string(),
+        "18: 15 15".to_string(),
+        "7: 14 5 | 1 21".to_string(),
+        "24: 14 1".to_string()];
+
+    let rule_dict = make_rules_dict(&rules).unwrap();
+
+    assert!(check_perfect_match(0, &rule_dict, "bbabbbbaabaabba"));
+}
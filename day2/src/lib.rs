@@ -0,0 +1,502 @@
+#[macro_use] extern crate lazy_static;
+use aoc_common::{parse_lines, parse_number, Answer, Details, Error, Result, Solution};
+use regex::Regex;
+use std::fmt;
+#[cfg(feature = "graphemes")]
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct Rule {
+    letter: String,
+    password: String,
+    min: usize,
+    max: usize,
+}
+
+/// The letters of `text`: its Unicode scalar values, or with the `graphemes` feature its grapheme
+/// clusters, so that a letter and the accents combined with it count as one.
+#[cfg(not(feature = "graphemes"))]
+fn letters(text: &str) -> Vec<&str> {
+    text.char_indices().map(|(i, c)| &text[i..i + c.len_utf8()]).collect()
+}
+
+#[cfg(feature = "graphemes")]
+fn letters(text: &str) -> Vec<&str> {
+    text.graphemes(true).collect()
+}
+
+/// The rule as it appears in the puzzle input.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{} {}: {}", self.min, self.max, self.letter, self.password)
+    }
+}
+
+fn extract_rule(line: &str) -> Result<Rule> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(?P<min>\d+)-(?P<max>\d+) (?P<letter>.+?): (?P<string>.+)$").unwrap();
+    }
+
+    let cap = RE.captures(line)
+        .ok_or_else(|| Error::parse(line, line, "expected `<min>-<max> <letter>: <password>`"))?;
+
+    let min_text = cap.name("min").unwrap().as_str();
+    let min = parse_number(line, min_text)?;
+    let max = parse_number(line, cap.name("max").unwrap().as_str())?;
+
+    if min == 0 {
+        return Err(Error::parse(line, min_text, "positions count from 1"));
+    }
+    if min > max {
+        return Err(Error::parse(line, min_text, format!("minimum {} is above maximum {}", min, max)));
+    }
+
+    let letter = cap.name("letter").unwrap().as_str();
+    if letters(letter).len() != 1 {
+        return Err(Error::parse(line, letter, format!("expected a single letter, not `{}`", letter)));
+    }
+
+    Ok(Rule {
+        letter: letter.to_string(),
+        password: cap["string"].to_string(),
+        min,
+        max,
+    })
+}
+
+/// Checks the letter occurs between the minimum and maximum number of times.
+fn check_password1(rule: &Rule) -> std::result::Result<(), String> {
+
+    let count = letters(&rule.password).into_iter().filter(|x| *x == rule.letter).count();
+
+    if (count >= rule.min) && (count <= rule.max) {
+        Ok(())
+    } else {
+        Err(format!("letter '{}' occurs {} times, allowed {}-{}", rule.letter, count, rule.min, rule.max))
+    }
+}
+
+/// Checks the letter is at exactly one of the two positions, which must both be in the password.
+fn check_password2(rule: &Rule) -> std::result::Result<(), String> {
+
+    let password_letters = letters(&rule.password);
+    let has_letter = |position: usize| {
+        password_letters.get(position - 1)
+            .map(|x| *x == rule.letter)
+            .ok_or_else(|| format!("position {} out of range for {}-char password", position, password_letters.len()))
+    };
+
+    match (has_letter(rule.min)?, has_letter(rule.max)?) {
+        (true, true) => Err(format!("letter '{}' is at both positions {} and {}", rule.letter, rule.min, rule.max)),
+        (false, false) => Err(format!("letter '{}' is at neither position {} nor {}", rule.letter, rule.min, rule.max)),
+        _ => Ok(()),
+    }
+}
+
+/// Something a password must satisfy. Each policy displays as its line in a policy file.
+pub trait PasswordPolicy: fmt::Display + Sync {
+    /// Checks the password of `rule`, saying why it breaks the policy if it does.
+    fn check(&self, rule: &Rule) -> std::result::Result<(), String>;
+
+    fn allows(&self, rule: &Rule) -> bool {
+        self.check(rule).is_ok()
+    }
+}
+
+/// The letter of the rule occurs between its minimum and maximum number of times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CountInRange;
+
+/// The letter of the rule is at exactly one of its two positions, counting from 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OnePosition;
+
+/// The password is at least `min` and at most `max` characters long.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Length {
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Symbol,
+}
+
+/// The password has at least one character of a class.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Requires(pub CharClass);
+
+/// The password does not contain a piece of text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Forbids(pub String);
+
+/// The password matches a regular expression somewhere.
+#[derive(Clone, Debug)]
+pub struct Matches(pub Regex);
+
+impl PasswordPolicy for CountInRange {
+    fn check(&self, rule: &Rule) -> std::result::Result<(), String> {
+        check_password1(rule)
+    }
+}
+
+impl PasswordPolicy for OnePosition {
+    fn check(&self, rule: &Rule) -> std::result::Result<(), String> {
+        check_password2(rule)
+    }
+}
+
+impl PasswordPolicy for Length {
+    fn check(&self, rule: &Rule) -> std::result::Result<(), String> {
+        let length = letters(&rule.password).len();
+
+        match (self.min, self.max) {
+            (Some(min), _) if length < min => Err(format!("{} chars, fewer than {}", length, min)),
+            (_, Some(max)) if length > max => Err(format!("{} chars, more than {}", length, max)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl CharClass {
+    const NAMES: [(&'static str, CharClass); 4] =
+        [("lower", CharClass::Lower), ("upper", CharClass::Upper), ("digit", CharClass::Digit), ("symbol", CharClass::Symbol)];
+
+    fn contains(self, c: char) -> bool {
+        match self {
+            CharClass::Lower => c.is_lowercase(),
+            CharClass::Upper => c.is_uppercase(),
+            CharClass::Digit => c.is_numeric(),
+            CharClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        CharClass::NAMES.iter().find(|(_, class)| *class == self).unwrap().0
+    }
+}
+
+impl PasswordPolicy for Requires {
+    fn check(&self, rule: &Rule) -> std::result::Result<(), String> {
+        if rule.password.chars().any(|c| self.0.contains(c)) {
+            Ok(())
+        } else {
+            Err(format!("no {} character", self.0.name()))
+        }
+    }
+}
+
+impl PasswordPolicy for Forbids {
+    fn check(&self, rule: &Rule) -> std::result::Result<(), String> {
+        match rule.password.find(&self.0) {
+            Some(at) => Err(format!("contains \"{}\" at char {}", self.0, letters(&rule.password[..at]).len() + 1)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl PasswordPolicy for Matches {
+    fn check(&self, rule: &Rule) -> std::result::Result<(), String> {
+        if self.0.is_match(&rule.password) {
+            Ok(())
+        } else {
+            Err(format!("does not match /{}/", self.0))
+        }
+    }
+}
+
+impl fmt::Display for CountInRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "count-in-range")
+    }
+}
+
+impl fmt::Display for OnePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "one-position")
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bound = |x: Option<usize>| x.map_or(String::new(), |x| x.to_string());
+
+        write!(f, "length {}-{}", bound(self.min), bound(self.max))
+    }
+}
+
+impl fmt::Display for Requires {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "requires {}", self.0.name())
+    }
+}
+
+impl fmt::Display for Forbids {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "forbids {}", self.0)
+    }
+}
+
+impl fmt::Display for Matches {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "matches {}", self.0)
+    }
+}
+
+fn parse_length(line: &str, bounds: &str) -> Result<Length> {
+    let (min, max) = bounds.split_once('-')
+        .ok_or_else(|| Error::parse(line, bounds, "expected `<min>-<max>`, either of which may be left out"))?;
+    let bound = |text: &str| if text.is_empty() { Ok(None) } else { parse_number(line, text).map(Some) };
+    let length = Length { min: bound(min)?, max: bound(max)? };
+
+    match length {
+        Length { min: Some(min), max: Some(max) } if min > max => Err(Error::parse(line, bounds, format!("minimum {} is above maximum {}", min, max))),
+        _ => Ok(length),
+    }
+}
+
+/// Reads one line of a policy file: a policy name, then its argument if it takes one. Errors
+/// point into the whole `line`, around any space the policy is indented or followed by.
+fn parse_policy(line: &str) -> Result<Box<dyn PasswordPolicy>> {
+    let policy = line.trim();
+    let end = &policy[policy.len()..];
+    let (name, argument) = policy.split_once(char::is_whitespace).unwrap_or((policy, end));
+    let argument = argument.trim();
+
+    let policy : Box<dyn PasswordPolicy> = match (name, argument) {
+        ("count-in-range", "") => Box::new(CountInRange),
+        ("one-position", "") => Box::new(OnePosition),
+        ("length", bounds) => Box::new(parse_length(line, bounds)?),
+        ("requires", class) => {
+            let names : Vec<_> = CharClass::NAMES.iter().map(|(name, _)| *name).collect();
+            let class = CharClass::NAMES.iter().find(|(name, _)| *name == class)
+                .ok_or_else(|| Error::parse(line, class, format!("expected a class: {}", names.join(", "))))?;
+            Box::new(Requires(class.1))
+        }
+        ("forbids", "") => return Err(Error::parse(line, end, "forbids needs text to forbid")),
+        ("forbids", text) => Box::new(Forbids(text.to_string())),
+        ("matches", "") => return Err(Error::parse(line, end, "matches needs a pattern")),
+        ("matches", pattern) => Box::new(Matches(Regex::new(pattern).map_err(|e| Error::parse(line, pattern, e.to_string()))?)),
+        ("count-in-range", extra) | ("one-position", extra) => return Err(Error::parse(line, extra, "takes no argument")),
+        _ => return Err(Error::parse(line, name, "expected count-in-range, one-position, length, requires, forbids or matches")),
+    };
+    Ok(policy)
+}
+
+/// Reads a policy file: one policy per line, as each displays, skipping blank lines and `#`
+/// comments. For example:
+///
+/// ```text
+/// # at least 8 characters, with a digit, and never "password"
+/// length 8-
+/// requires digit
+/// forbids password
+/// matches ^[a-z0-9]+$
+/// ```
+pub fn parse_policies(input: &str) -> Result<Vec<Box<dyn PasswordPolicy>>> {
+    let policies = parse_lines(input, |line| {
+        let policy = line.trim();
+        if policy.is_empty() || policy.starts_with('#') { Ok(None) } else { parse_policy(line).map(Some) }
+    })?;
+
+    Ok(policies.into_iter().flatten().collect())
+}
+
+/// A policy a password breaks, and why.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub policy: String,
+    pub reason: String,
+}
+
+/// The policies each password breaks, in the order they are given.
+pub fn audit(rules: &[Rule], policies: &[Box<dyn PasswordPolicy>]) -> Vec<Vec<Violation>> {
+    rules.iter()
+        .map(|rule| {
+            policies.iter()
+                .filter_map(|policy| policy.check(rule).err().map(|reason| Violation { policy: policy.to_string(), reason }))
+                .collect()
+        })
+        .collect()
+}
+
+/// One row per line of input: `ok` for a password that follows every policy, otherwise one row
+/// per policy it breaks, each with the reason.
+pub fn audit_table(rules: &[Rule], audits: &[Vec<Violation>]) -> String {
+    let width = rules.iter().map(|rule| rule.to_string().chars().count()).max().unwrap_or(0).max(4);
+    let mut table = format!("{:>5}  {:<width$}  {:<20}  Reason\n", "Line", "Rule", "Policy", width = width);
+
+    for (i, (rule, violations)) in rules.iter().zip(audits).enumerate() {
+        if violations.is_empty() {
+            table += &format!("{:>5}  {:<width$}  ok\n", i + 1, rule.to_string(), width = width);
+        }
+        for violation in violations {
+            table += &format!("{:>5}  {:<width$}  {:<20}  {}\n", i + 1, rule.to_string(), violation.policy, violation.reason, width = width);
+        }
+    }
+    table
+}
+
+/// Quotes a CSV field when it holds a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The rows of `audit_table` as CSV, with `line,rule,valid,policy,reason` columns.
+pub fn audit_csv(rules: &[Rule], audits: &[Vec<Violation>]) -> String {
+    let mut csv = String::from("line,rule,valid,policy,reason\n");
+
+    for (i, (rule, violations)) in rules.iter().zip(audits).enumerate() {
+        let rule = csv_field(&rule.to_string());
+        if violations.is_empty() {
+            csv += &format!("{},{},true,,\n", i + 1, rule);
+        }
+        for violation in violations {
+            csv += &format!("{},{},false,{},{}\n", i + 1, rule, csv_field(&violation.policy), csv_field(&violation.reason));
+        }
+    }
+    csv
+}
+
+/// How many passwords follow every one of `policies`.
+pub fn count_valid(rules: &[Rule], policies: &[Box<dyn PasswordPolicy>]) -> usize {
+    rules.iter().filter(|rule| policies.iter().all(|policy| policy.allows(rule))).count()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Rule>;
+
+    fn parse(input: &str) -> Result<Vec<Rule>> {
+        parse_lines(input, extract_rule)
+    }
+
+    fn part1(rules: &Vec<Rule>) -> Answer {
+        count_valid(rules, &[Box::new(CountInRange)]).into()
+    }
+
+    fn part2(rules: &Vec<Rule>) -> Answer {
+        count_valid(rules, &[Box::new(OnePosition)]).into()
+    }
+
+    fn details(rules: &Vec<Rule>, _part: u8) -> Details {
+        vec![("passwords", rules.len().into())]
+    }
+}
+
+#[test]
+fn reads_policy_files () {
+    let text = "# house rules\n\nlength  8-\nrequires\tdigit\nforbids  abc \nmatches ^[a-z0-9]+$\n  one-position";
+    let policies : Vec<String> = parse_policies(text).unwrap().iter().map(|x| x.to_string()).collect();
+
+    assert_eq!(policies, vec!["length 8-", "requires digit", "forbids abc", "matches ^[a-z0-9]+$", "one-position"]);
+
+    for (text, position) in [("length 9-3", (1, 8)), ("requires vowel", (1, 10)), ("matches (", (1, 9)), ("count-in-range 3", (1, 16)), ("\nmaybe", (2, 1)),
+                             ("forbids", (1, 8)), ("forbids  ", (1, 8)), ("matches", (1, 8)), ("length  8", (1, 9)),
+                             ("  one-position x", (1, 16)), ("length", (1, 7)), ("\t length ", (1, 9))] {
+        match parse_policies(text) {
+            Err(Error::Parse(error)) => {
+                assert_eq!((error.line, error.column), position, "{:?}", text);
+                assert_eq!(error.text, text.lines().nth(error.line - 1).unwrap(), "{:?}", text);
+            }
+            _ => panic!("{:?} should not parse", text),
+        }
+    }
+}
+
+#[test]
+fn audits_rules_against_policies () {
+    let rules = parse_lines("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n1-2 x: xxsecret", extract_rule).unwrap();
+    let count = |policies| count_valid(&rules, &parse_policies(policies).unwrap());
+
+    assert_eq!(count("count-in-range"), 3);
+    assert_eq!(count("one-position"), 1);
+    assert_eq!(count("length 6-\nrequires lower"), 2);
+    assert_eq!(count("length -5"), 2);
+    assert_eq!(count("forbids cc\nmatches ^[a-e]"), 2);
+    assert_eq!(count(""), 4);
+}
+
+#[test]
+fn explains_broken_policies () {
+    let rules = parse_lines("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n3-9 d: ddd", extract_rule).unwrap();
+    let reasons = |policy: &dyn PasswordPolicy| -> Vec<_> { rules.iter().map(|rule| policy.check(rule).err()).collect() };
+
+    assert_eq!(reasons(&CountInRange), vec![None, Some("letter 'b' occurs 0 times, allowed 1-3".to_string()), None, None]);
+    assert_eq!(reasons(&OnePosition), vec![
+        None,
+        Some("letter 'b' is at neither position 1 nor 3".to_string()),
+        Some("letter 'c' is at both positions 2 and 9".to_string()),
+        Some("position 9 out of range for 3-char password".to_string()),
+    ]);
+    assert_eq!(reasons(&Forbids("cd".to_string()))[0], Some("contains \"cd\" at char 3".to_string()));
+}
+
+#[test]
+fn writes_audit_reports () {
+    let rules = parse_lines("1-3 a: abcde\n1-3 b: cdefg", extract_rule).unwrap();
+    let policies = parse_policies("count-in-range\nmatches ^(a|b),?").unwrap();
+    let audits = audit(&rules, &policies);
+
+    assert_eq!(audit_table(&rules, &audits).lines().collect::<Vec<_>>(), vec![
+        " Line  Rule          Policy                Reason",
+        "    1  1-3 a: abcde  ok",
+        "    2  1-3 b: cdefg  count-in-range        letter 'b' occurs 0 times, allowed 1-3",
+        "    2  1-3 b: cdefg  matches ^(a|b),?      does not match /^(a|b),?/",
+    ]);
+    assert_eq!(audit_csv(&rules, &audits), "\
+line,rule,valid,policy,reason
+1,1-3 a: abcde,true,,
+2,1-3 b: cdefg,false,count-in-range,\"letter 'b' occurs 0 times, allowed 1-3\"
+2,1-3 b: cdefg,false,\"matches ^(a|b),?\",\"does not match /^(a|b),?/\"
+");
+}
+
+#[test]
+fn reads_multibyte_letters_and_positions () {
+    let rules = parse_lines("1-3 é: caféé\n2-4 ü: aü\n1-2 🦀: 🦀x\n1-3 :: a:b:", extract_rule).unwrap();
+    let reasons = |policy: &dyn PasswordPolicy| -> Vec<_> { rules.iter().map(|rule| policy.check(rule).err()).collect() };
+
+    assert_eq!(rules[0].to_string(), "1-3 é: caféé");
+    assert_eq!(reasons(&CountInRange), vec![None, Some("letter 'ü' occurs 1 times, allowed 2-4".to_string()), None, None]);
+    assert_eq!(reasons(&OnePosition), vec![
+        Some("letter 'é' is at neither position 1 nor 3".to_string()),
+        Some("position 4 out of range for 2-char password".to_string()),
+        None,
+        Some("letter ':' is at neither position 1 nor 3".to_string()),
+    ]);
+    assert_eq!(reasons(&Forbids("éé".to_string()))[0], Some("contains \"éé\" at char 4".to_string()));
+    assert_eq!(Length { min: None, max: Some(2) }.check(&rules[1]), Ok(()));
+
+    match extract_rule("1-3 ab: abc") {
+        Err(Error::Parse(error)) => assert_eq!(error.column, 5),
+        _ => panic!("two letters should not parse"),
+    }
+}
+
+#[cfg(not(feature = "graphemes"))]
+#[test]
+fn counts_combining_accents_as_letters () {
+    let rule = extract_rule("1-3 e: e\u{301}e").unwrap();
+
+    assert_eq!(OnePosition.check(&rule), Err("letter 'e' is at both positions 1 and 3".to_string()));
+    assert!(extract_rule("1-2 e\u{301}: e\u{301}").is_err());
+}
+
+#[cfg(feature = "graphemes")]
+#[test]
+fn counts_grapheme_clusters_as_letters () {
+    let rule = extract_rule("1-2 e\u{301}: e\u{301}e").unwrap();
+
+    assert_eq!(OnePosition.check(&rule), Ok(()));
+    assert_eq!(CountInRange.check(&rule), Ok(()));
+    assert_eq!(Length { min: Some(3), max: None }.check(&rule), Err("2 chars, fewer than 3".to_string()));
+}
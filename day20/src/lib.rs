@@ -0,0 +1,329 @@
+#[macro_use] extern crate lazy_static;
+use aoc_common::{parse_cells, parse_number, records, Grid, Record, Answer, Details, Error, Frame, Result, Solution};
+use log::{debug, trace};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use regex::Regex;
+
+#[derive(EnumIter, Clone, Debug, PartialEq)]
+enum Direction {
+    North,
+    East,
+    South,
+    West
+} 
+
+type Edge = Vec<bool>;
+type Image = Grid<bool>;
+
+#[derive(Hash, Copy, Clone, Debug, PartialEq, Eq)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    fn new(x: i32, y:i32) -> Position {
+        Position{x, y}
+    }
+
+    fn neighbors( &self ) -> Vec<Position> {
+        vec![self.north(), self.east(), self.south(), self.west()]
+    }
+
+    fn get_ajacent_position(&self, direction: &Direction) -> Position {
+        match direction {
+            Direction::North => self.north(),
+            Direction::East => self.east(),
+            Direction::South => self.south(),
+            Direction::West => self.west() }
+    }
+
+    fn north(&self) -> Position {
+        Position::new(self.x, self.y-1)
+    }
+
+    fn east(&self) -> Position {
+        Position::new(self.x+1, self.y)
+    }
+
+    fn south(&self) -> Position {
+        Position::new(self.x, self.y+1)
+    }
+
+    fn west(&self) -> Position {
+        Position::new(self.x-1, self.y)
+    }
+}
+
+#[derive(Clone)]
+pub struct MapTile {
+    id: u32,
+    image: Image,
+}
+
+impl MapTile {
+    fn new( id: u32, image: Image ) -> MapTile {
+        MapTile{
+            id,
+            image,
+        }
+    }
+
+    fn get_edge(&self, direction: &Direction) -> Edge {
+        match direction {
+            Direction::North => self.image.row(0).to_vec(),
+            Direction::East => self.image.column(self.image.width() - 1).cloned().collect(),
+            Direction::South => self.image.row(self.image.height() - 1).to_vec(),
+            Direction::West => self.image.column(0).cloned().collect(),
+        }
+    }
+
+    /// The eight ways the tile can be laid down: each quarter turn, mirrored and as is.
+    fn orientations(&self) -> Vec<MapTile> {
+        let mut orientations = Vec::new();
+        let mut image = self.image.clone();
+
+        for _ in 0..4 {
+            let rotated = image.rotate_cw();
+            orientations.push(MapTile::new(self.id, image.flip_horizontal()));
+            orientations.push(MapTile::new(self.id, image));
+            image = rotated;
+        }
+        orientations
+    }
+}
+
+struct Map {
+    positions: HashMap<Position, Option<MapTile>>,
+}
+
+impl Map {
+
+    fn new() -> Map {
+        Map{
+            positions: HashMap::new(),
+        }
+    }
+
+    fn add_tile(mut self, pos: &Position, tile: MapTile) -> Map  {
+        self.positions.insert(*pos, Some(tile));
+        self._spawn_neighbors(pos);
+        self
+    }
+    
+    fn _spawn_neighbors(&mut self, pos: &Position) {
+        for neighbor_pos in pos.neighbors().iter() {
+            if !self.positions.contains_key(neighbor_pos) {
+                self.positions.insert(*neighbor_pos, None);
+            }
+        }
+    }
+    
+    fn get_tile_at_pos(&self, pos: &Position) -> Option<&MapTile> {
+        self.positions.get(pos).and_then(|tile| tile.as_ref())
+    }
+    
+    fn get_occupied_positions(&self) -> impl Iterator<Item=Position> + '_ {
+        self.positions.keys().filter(move |key| self.positions[key].is_some()).cloned()
+    }
+
+    fn get_empty_positions(&self) -> impl Iterator<Item=Position> + '_ {
+        self.positions.keys().filter(move |key| self.positions[key].is_none()).cloned()
+    }
+    
+}
+
+fn check_edge_match(base_edge: Edge, candidate: Option<Edge>) -> bool {
+    trace!("        {:?} + {:?}", base_edge, candidate);
+
+    
+    match candidate {
+        Some(edge) => base_edge == edge,
+        None => true,
+    }
+}
+
+fn get_ajacent_edge_in_direction(map: &Map, pos:&Position, direction: &Direction) -> Option<Edge> {
+    let adj_pos = pos.get_ajacent_position(direction);
+    let mirrored_edge = match direction {
+        Direction::North => Direction::South,
+        Direction::East => Direction::West,
+        Direction::South => Direction::North,
+        Direction::West => Direction::East,
+    };
+
+    if let Some(adj_tile) = map.get_tile_at_pos(&adj_pos) {
+        trace!("      Looking at the {:?} Edge of {} ({:?})", mirrored_edge, adj_tile.id, adj_pos);
+    }
+
+    map.get_tile_at_pos(&adj_pos).map(|tile| tile.get_edge(&mirrored_edge))
+}
+
+fn check_match_in_direction(new_tile: &MapTile, map: &Map, pos: &Position, direction: &Direction ) -> bool {
+    trace!("      Checking Edge {:?} of {:?}", direction, pos);
+    check_edge_match(new_tile.get_edge(direction), get_ajacent_edge_in_direction(map, pos, direction))
+}
+
+fn check_match(new_tile: &MapTile, map: &Map, pos: &Position ) -> bool {
+    Direction::iter()
+        .all(|dir| check_match_in_direction(new_tile, map, pos, &dir))
+}
+
+fn try_rotate_and_match(new_tile: &MapTile, map: &Map,) -> Option<(Position, MapTile)> {
+
+    trace!("Attempting to fit {}", new_tile.id);
+
+    let empty_positions : Vec<Position> = map.get_empty_positions().collect();
+
+    for pos in empty_positions.iter() {
+        trace!("  Trying Position {:?}", pos);
+        for orientation in new_tile.orientations() {
+            if check_match(&orientation, map, pos) {
+                return Some((*pos, orientation));
+            }
+        }
+    }
+
+    trace!("  Unable to fit at this time");
+    None
+}
+
+fn id_at_position(position: &Position, map: &Map) -> u32 {
+    map.get_tile_at_pos(position).unwrap().id
+}
+
+/// Lays every tile down next to ones whose edges it matches.
+fn assemble(tiles: &[MapTile]) -> Option<Map> {
+    let mut map = Map::new();
+
+    let mut tile_refs : VecDeque<&MapTile> = tiles.iter().collect();
+
+    map = map.add_tile(
+        &Position::new(0,0), 
+        tile_refs.pop_front()?.clone());
+
+    while !tile_refs.is_empty() {
+        let tile : &MapTile = tile_refs.pop_front().unwrap();
+
+        if let Some((pos, tile)) = try_rotate_and_match(tile, &map) {
+            debug!("Fitting {} to {:?}", tile.id, pos);
+            map = map.add_tile(&pos, tile);
+        }
+        else {
+            tile_refs.push_back(tile);
+        }
+    }
+
+    Some(map)
+}
+
+/// The top left and bottom right positions of the assembled tiles.
+fn bounds(map: &Map) -> (Position, Position) {
+    let x_max = map.get_occupied_positions().map(|pos| pos.x).max().unwrap();
+    let x_min = map.get_occupied_positions().map(|pos| pos.x).min().unwrap();
+    let y_max = map.get_occupied_positions().map(|pos| pos.y).max().unwrap();
+    let y_min = map.get_occupied_positions().map(|pos| pos.y).min().unwrap();
+
+    (Position::new(x_min, y_min), Position::new(x_max, y_max))
+}
+
+/// Assembles the tiles into a map and returns the ids of its corners, clockwise from the top left.
+fn find_corners(tiles: &[MapTile]) -> Option<[u32; 4]> {
+    let map = assemble(tiles)?;
+    let (min, max) = bounds(&map);
+
+    let corners = [id_at_position(&Position::new(min.x, min.y), &map),
+                   id_at_position(&Position::new(max.x, min.y), &map),
+                   id_at_position(&Position::new(max.x, max.y), &map),
+                   id_at_position(&Position::new(min.x, max.y), &map)];
+
+    Some(corners)
+}
+
+/// The assembled tiles side by side, borders and all.
+fn assembled_image(map: &Map) -> Image {
+    let (min, max) = bounds(map);
+    let columns = (max.x - min.x + 1) as usize;
+    let rows = (max.y - min.y + 1) as usize;
+
+    Grid::from_fn(columns * TILE_SIZE, rows * TILE_SIZE, |x, y| {
+        let position = Position::new(min.x + (x / TILE_SIZE) as i32, min.y + (y / TILE_SIZE) as i32);
+        map.get_tile_at_pos(&position).is_some_and(|tile| tile.image[(x % TILE_SIZE, y % TILE_SIZE)])
+    })
+}
+
+const TILE_SIZE: usize = 10;
+
+fn parse_tile_id(line: &str) -> Result<u32> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^Tile (\d+):$").unwrap();
+    }
+
+    let cap = RE.captures(line)
+        .ok_or_else(|| Error::parse(line, line, "expected `Tile <id>:`"))?;
+
+    parse_number(line, cap.get(1).unwrap().as_str())
+}
+
+fn parse_image_row(line: &str) -> Result<Vec<bool>> {
+    let row = parse_cells(line)?;
+
+    if row.len() != TILE_SIZE {
+        return Err(Error::parse(line, line, format!("expected {} pixels, found {}", TILE_SIZE, row.len())));
+    }
+    Ok(row)
+} 
+
+fn parse_tile(record: &Record) -> Result<MapTile> {
+    let id = record.parse_line(0, parse_tile_id)?;
+    let header = &record.lines[0];
+
+    if record.lines.len() != TILE_SIZE + 1 {
+        return Err(Error::parse(header, header, format!("expected {} rows, found {}", TILE_SIZE, record.lines.len() - 1))
+            .offset_lines(record.line - 1));
+    }
+
+    let image = (1..=TILE_SIZE).map(|i| record.parse_line(i, parse_image_row)).collect::<Result<_>>()?;
+    Ok(MapTile::new(id, Grid::from_rows(image).expect("rows are checked to be the same width")))
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<MapTile>> {
+    records(input.as_bytes())
+        .map(|record| parse_tile(&record?))
+        .collect()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<MapTile>;
+
+    fn parse(input: &str) -> Result<Vec<MapTile>> {
+        parse_tiles(input)
+    }
+
+    fn part1(tiles: &Vec<MapTile>) -> Answer {
+        find_corners(tiles).map_or(Answer::Unsolved, |corners| corners.iter().map(|x| *x as u64).product::<u64>().into())
+    }
+
+    fn part2(_tiles: &Vec<MapTile>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn details(tiles: &Vec<MapTile>, part: u8) -> Details {
+        match (part, find_corners(tiles)) {
+            (1, Some(corners)) => vec![("corners", corners.to_vec().into())],
+            _ => Vec::new(),
+        }
+    }
+
+    fn frames(tiles: &Vec<MapTile>, part: u8) -> Vec<Frame> {
+        match (part, assemble(tiles)) {
+            (1, Some(map)) => vec![Frame::from_grid(format!("{} tiles assembled", tiles.len()), &assembled_image(&map))],
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use aoc_common::{parse_number, Answer, Error, Frame, Grid, Result, Solution};
+use log::{debug, warn};
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::RangeFrom;
+use std::str::FromStr;
+
+/// The map of the slope, one bit per square set where there is a tree, each row starting a new
+/// word so that any square is found in one lookup however far right the forest is repeated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Forest {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+
+impl Forest {
+    /// The forest with a tree wherever `grid` is set, or `None` if the grid has no squares.
+    pub fn from_grid(grid: &Grid<bool>) -> Option<Forest> {
+        if grid.width() == 0 || grid.height() == 0 {
+            return None;
+        }
+
+        let words_per_row = grid.width().div_ceil(64);
+        let mut bits = vec![0; words_per_row * grid.height()];
+
+        for (x, y) in grid.positions().filter(|position| grid[*position]) {
+            bits[y * words_per_row + x / 64] |= 1 << (x % 64);
+        }
+        Some(Forest { width: grid.width(), height: grid.height(), words_per_row, bits })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether there is a tree at `(column, row)`, with the forest repeating endlessly to the
+    /// right.
+    pub fn is_tree(&self, column: usize, row: usize) -> bool {
+        assert!(row < self.height, "row {} of a forest {} tall", row, self.height);
+        let column = column % self.width;
+
+        self.bits[row * self.words_per_row + column / 64] >> (column % 64) & 1 == 1
+    }
+}
+
+/// Reads the forest as a grid of `#` trees and `.` open squares, which must have at least one
+/// square.
+impl FromStr for Forest {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Forest> {
+        let grid : Grid<bool> = input.parse()?;
+
+        Forest::from_grid(&grid).ok_or_else(|| Error::parse(input, input, "the forest is empty"))
+    }
+}
+
+/// How far the toboggan goes each step: `right / per` columns to the right, negative going left,
+/// and `down` rows down. It only lands where a step ends on a whole column, so going right 1 per
+/// 3 steps down 1 row lands on the same squares as going right 1 and down 3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Slope {
+    right: isize,
+    per: usize,
+    down: usize,
+}
+
+impl Slope {
+    /// A slope of whole columns.
+    pub const fn new(right: isize, down: usize) -> Slope {
+        Slope { right, per: 1, down }
+    }
+
+    /// A slope of `right / per` columns, or `None` if `per` is 0 or the step between landings is
+    /// too long to count in rows.
+    pub fn fraction(right: isize, per: usize, down: usize) -> Option<Slope> {
+        let slope = Slope { right, per, down };
+        if per == 0 || slope.rows_per_step().is_none() {
+            return None;
+        }
+        Some(slope)
+    }
+
+    /// How many columns a step goes right, negative going left, before reducing the fraction.
+    pub fn right(self) -> isize {
+        self.right
+    }
+
+    /// How many rows a step goes down, before reducing the fraction.
+    pub fn down(self) -> usize {
+        self.down
+    }
+
+    fn common(self) -> usize {
+        let gcd = |mut a: usize, mut b: usize| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        gcd(self.right.unsigned_abs(), self.per)
+    }
+
+    fn rows_per_step(self) -> Option<usize> {
+        self.down.checked_mul(self.per / self.common())
+    }
+
+    /// The shortest whole step, in columns right and rows down, between squares landed on.
+    pub fn step(self) -> (isize, usize) {
+        let rows = self.rows_per_step().expect("slopes are checked when made");
+
+        ((self.right as i128 / self.common() as i128) as isize, rows)
+    }
+}
+
+/// The five slopes checked in part 2, the second of which is part 1's.
+pub const PUZZLE_SLOPES: [Slope; 5] = [Slope::new(1, 1), Slope::new(3, 1), Slope::new(5, 1), Slope::new(7, 1), Slope::new(1, 2)];
+
+/// Reads a slope written `<right>,<down>`, such as `3,1` or `-2,1`, where `<right>` may be a
+/// fraction, such as `1/3,1`.
+impl FromStr for Slope {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Slope> {
+        let (right, down) = text.split_once(',')
+            .ok_or_else(|| Error::parse(text, text, "expected `<right>,<down>`"))?;
+        let (right, per) = right.split_once('/').unwrap_or((right, "1"));
+        let (right_text, per_text, down_text) = (right, per, down);
+        let (right, per, down) = (parse_number(text, right_text)?, parse_number(text, per_text)?, parse_number(text, down_text)?);
+
+        if per == 0 {
+            return Err(Error::parse(text, per_text, "a fraction cannot be over 0"));
+        }
+        if down == 0 {
+            return Err(Error::parse(text, down_text, "the toboggan has to go down"));
+        }
+        Slope::fraction(right, per, down)
+            .ok_or_else(|| Error::parse(text, down_text, "the toboggan goes too far down between landings"))
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.per {
+            1 => write!(f, "right {}, down {}", self.right, self.down),
+            per => write!(f, "right {}/{}, down {}", self.right, per, self.down),
+        }
+    }
+}
+
+/// The `(column, row)` of every square the toboggan lands on, from the top left to the bottom,
+/// with columns counted along the forest as it repeats to either side.
+fn landings(forest: &Forest, slope: Slope) -> impl Iterator<Item=(isize, usize)> {
+    let (right, down) = slope.step();
+    assert!(down > 0, "the toboggan has to go down");
+
+    (0..forest.height()).step_by(down)
+        .zip(RangeFrom{ start:0isize })
+        .map_while(move |(row, x)| Some((x.checked_mul(right)?, row)))
+}
+
+/// How many trees the toboggan hits going `right`, or left when negative, and `down` at a time
+/// from the top left.
+///
+/// Panics if `down` is 0.
+pub fn count_trees(forest: &Forest, right: isize, down: usize) -> usize {
+    assert!(down > 0, "the toboggan has to go down");
+    let step = right.rem_euclid(forest.width() as isize) as usize;
+
+    let columns = std::iter::successors(Some(0), |column| Some((column + step) % forest.width()));
+    (0..forest.height()).step_by(down).zip(columns).filter(|(row, column)| forest.is_tree(*column, *row)).count()
+}
+
+/// How many trees the toboggan hits going down `slope` from the top left.
+pub fn count_trees_on(forest: &Forest, slope: Slope) -> usize {
+    let (right, down) = slope.step();
+
+    count_trees(forest, right, down)
+}
+
+/// A slope and the trees hit going down it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SlopeTrees {
+    pub slope: Slope,
+    pub trees: usize,
+}
+
+/// The slopes hitting the fewest and the most trees of every slope of whole columns going between
+/// straight down and `max_right` across, and from 1 to `max_down` down, taking the first found of
+/// any tie, going across first. Going across the whole width or more lands on the same squares as
+/// going less than that, and going down past the bottom lands on the top row alone, so slopes past
+/// those are left out.
+///
+/// Panics if `max_down` is 0.
+pub fn search_slopes(forest: &Forest, max_right: isize, max_down: usize) -> (SlopeTrees, SlopeTrees) {
+    let across = forest.width() as isize - 1;
+    let rights = max_right.clamp(-across, 0)..=max_right.clamp(0, across);
+    let max_down = max_down.min(forest.height());
+    let mut tried = (1..=max_down)
+        .flat_map(|down| rights.clone().map(move |right| Slope::new(right, down)))
+        .map(|slope| SlopeTrees { slope, trees: count_trees_on(forest, slope) });
+
+    let first = tried.next().expect("the toboggan has to go down");
+    tried.fold((first, first), |(fewest, most), x| {
+        (if x.trees < fewest.trees { x } else { fewest }, if x.trees > most.trees { x } else { most })
+    })
+}
+
+/// The most squares `render_path` draws.
+pub const MAX_RENDER_SQUARES: u128 = 1 << 24;
+
+/// The forest repeated to either side as far as the toboggan gets going down `slope`, with the
+/// squares it lands on marked `O`, or `X` where it hits a tree, as drawn in the puzzle, or `None`
+/// if that takes more than `MAX_RENDER_SQUARES` squares.
+pub fn render_path(forest: &Forest, slope: Slope) -> Option<Grid<char>> {
+    let (step, down) = slope.step();
+    let width = forest.width() as i128;
+    let last = ((forest.height() - 1) / down) as i128 * step as i128;
+    let (left, right) = (((-last).max(0) + width - 1) / width, last.max(0) / width + 1);
+    if (left + right) as u128 * width as u128 * forest.height() as u128 > MAX_RENDER_SQUARES {
+        return None;
+    }
+
+    let landings : HashSet<(isize, usize)> = landings(forest, slope).collect();
+    let (left, width) = (left as isize, width as isize);
+    Some(Grid::from_fn((left + right as isize) as usize * forest.width(), forest.height(), |x, y| {
+        let column = x as isize - left * width;
+        match (landings.contains(&(column, y)), forest.is_tree(column.rem_euclid(width) as usize, y)) {
+            (true, true) => 'X',
+            (true, false) => 'O',
+            (false, true) => '#',
+            (false, false) => '.',
+        }
+    }))
+}
+
+fn path_frame(forest: &Forest, slope: Slope) -> Option<Frame> {
+    let path = render_path(forest, slope);
+    if path.is_none() {
+        warn!("The path going {} is too wide to draw", slope);
+    }
+
+    path.map(|path| {
+        let trees = path.iter().filter(|c| **c == 'X').count();
+        Frame::from_grid(format!("Going {}: {} trees", slope, trees), &path)
+    })
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Forest;
+
+    fn parse(input: &str) -> Result<Forest> {
+        input.parse()
+    }
+
+    fn part1(forest: &Forest) -> Answer {
+        count_trees(forest, 3, 1).into()
+    }
+
+    fn part2(forest: &Forest) -> Answer {
+        let counts : Vec<usize> = PUZZLE_SLOPES.iter().map(|slope| count_trees_on(forest, *slope)).collect();
+        debug!("Trees hit on each slope: {:?}", counts);
+        counts.iter().try_fold(1usize, |product, x| product.checked_mul(*x)).map_or(Answer::Unsolved, |x| x.into())
+    }
+
+    fn frames(forest: &Forest, part: u8) -> Vec<Frame> {
+        match part {
+            1 => path_frame(forest, Slope::new(3, 1)).into_iter().collect(),
+            2 => PUZZLE_SLOPES.iter().filter_map(|slope| path_frame(forest, *slope)).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+#[test]
+fn counts_trees_on_each_slope () {
+    let forest : Forest = EXAMPLE.parse().unwrap();
+    let counts : Vec<usize> = PUZZLE_SLOPES.iter().map(|slope| count_trees_on(&forest, *slope)).collect();
+
+    assert_eq!(counts, vec![2, 7, 3, 4, 2]);
+    assert_eq!(count_trees(&forest, 0, 1), 3);
+}
+
+#[test]
+fn reads_slopes () {
+    assert_eq!("3,1".parse::<Slope>().unwrap(), Slope::new(3, 1));
+    assert_eq!("-1,2".parse::<Slope>().unwrap(), Slope::new(-1, 2));
+    assert_eq!("1/3,1".parse::<Slope>().unwrap(), Slope { right: 1, per: 3, down: 1 });
+    assert_eq!("1/3,1".parse::<Slope>().unwrap().to_string(), "right 1/3, down 1");
+    for text in ["3", "3,0", "x,2", "1/0,1", "1/-3,1", "1/6,3074457345618258603", "1/9223372036854775808,9223372036854775807"] {
+        assert!(text.parse::<Slope>().is_err(), "{:?} should not parse", text);
+    }
+}
+
+#[test]
+fn finds_the_fewest_and_most_trees () {
+    let forest : Forest = EXAMPLE.parse().unwrap();
+    let (fewest, most) = search_slopes(&forest, 7, 2);
+
+    assert_eq!(fewest, SlopeTrees { slope: Slope::new(5, 2), trees: 0 });
+    assert_eq!(most, SlopeTrees { slope: Slope::new(3, 1), trees: 7 });
+    assert_eq!(search_slopes(&forest, isize::MAX, usize::MAX), search_slopes(&forest, 10, 11));
+    assert_eq!(search_slopes(&forest, isize::MIN, 3), search_slopes(&forest, -10, 3));
+}
+
+#[test]
+fn wraps_wide_forests_in_one_lookup () {
+    let grid = Grid::from_fn(130, 3, |x, y| x % 7 == y);
+    let forest = Forest::from_grid(&grid).unwrap();
+
+    assert_eq!(forest.bits.len(), 9);
+    assert!(grid.positions().all(|(x, y)| forest.is_tree(x, y) == grid[(x, y)]));
+    assert_eq!(forest.is_tree(129 + 130 * 1000, 2), grid[(129, 2)]);
+    assert_eq!(count_trees(&forest, isize::MAX, 1), count_trees(&forest, isize::MAX % 130, 1));
+    assert_eq!(count_trees(&forest, isize::MIN, 1), count_trees(&forest, isize::MIN.rem_euclid(130), 1));
+}
+
+#[test]
+fn checks_the_forest_when_it_is_read () {
+    for input in ["", "..#\n.x.", "..#\n.#"] {
+        assert!(input.parse::<Forest>().is_err(), "{:?} should not parse", input);
+    }
+    assert_eq!(Forest::from_grid(&Grid::from_fn(0, 3, |_, _| true)), None);
+    assert_eq!(Forest::from_grid(&Grid::from_fn(3, 0, |_, _| true)), None);
+}
+
+#[test]
+fn lands_only_on_whole_columns () {
+    let forest : Forest = EXAMPLE.parse().unwrap();
+
+    assert_eq!(Slope { right: 1, per: 3, down: 1 }.step(), (1, 3));
+    assert_eq!(Slope { right: -4, per: 6, down: 2 }.step(), (-2, 6));
+    assert_eq!(Slope { right: 0, per: 5, down: 1 }.step(), (0, 1));
+    assert_eq!(Slope::fraction(isize::MIN, 1 << 63, 1).unwrap().step(), (-1, 1));
+    assert_eq!(Slope::fraction(1, 0, 1), None);
+    assert_eq!(Slope::fraction(1, 3, usize::MAX), None);
+    assert_eq!(count_trees_on(&forest, Slope { right: 2, per: 2, down: 1 }), count_trees(&forest, 1, 1));
+    assert_eq!(count_trees_on(&forest, Slope { right: 1, per: 2, down: 1 }), count_trees(&forest, 1, 2));
+}
+
+#[test]
+fn wraps_leftward_slopes () {
+    let forest : Forest = EXAMPLE.parse().unwrap();
+
+    assert_eq!(count_trees(&forest, -3, 1), count_trees(&forest, 8, 1));
+    assert_eq!(count_trees(&forest, -14, 1), count_trees(&forest, 8, 1));
+}
+
+#[test]
+fn draws_the_path_as_in_the_puzzle () {
+    let forest : Forest = "..##.\n#...#\n.#...\n..#.#".parse().unwrap();
+
+    let lines = |slope| render_path(&forest, slope).unwrap().to_string().lines().map(String::from).collect::<Vec<_>>();
+
+    assert_eq!(lines(Slope::new(3, 1)), vec!["O.##...##.", "#..O##...#", ".#....X...", "..#.#..#.X"]);
+    assert_eq!(lines(Slope::new(-2, 1)), vec!["..##...##.O.##.", "#...##..O##...#", ".#....X....#...", "..#.X..#.#..#.#"]);
+    assert_eq!(lines(Slope { right: 1, per: 2, down: 1 }), vec!["O.##.", "#...#", ".X...", "..#.#"]);
+}
+
+#[test]
+fn refuses_to_draw_paths_too_wide () {
+    let forest : Forest = EXAMPLE.parse().unwrap();
+
+    assert!(render_path(&forest, Slope::new(isize::MAX, 1)).is_none());
+    assert!(render_path(&forest, Slope::new(isize::MIN, 1)).is_none());
+    assert!(render_path(&forest, Slope::new(1_000_000, 1)).is_none());
+    assert!(render_path(&forest, Slope::new(isize::MAX, 11)).is_some());
+}
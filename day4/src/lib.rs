@@ -0,0 +1,118 @@
+#[macro_use] extern crate lazy_static;
+use aoc_common::{records, Answer, Details, Error, Result, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+
+fn extract_kvps(line: &str) -> Result<Vec<(String, String)>> {
+    line.split_whitespace().map(|field| {
+        match field.split_once(':') {
+            Some((key, value)) if !key.is_empty() && !value.is_empty() && !value.contains(':') =>
+                Ok((key.to_string(), value.to_string())),
+            _ => Err(Error::parse(line, field, "expected `<key>:<value>`")),
+        }
+    }).collect()
+}
+
+fn extract_passport_data(input: &str) -> Result<Vec<HashMap<String, String>>> {
+    records(input.as_bytes())
+        .map(|record| Ok(record?.parse_lines(extract_kvps)?.into_iter().flatten().collect()))
+        .collect()
+}
+
+fn is_valid_passport1(passport_data: &HashMap<String, String>) -> bool {
+    let keys : Vec<&str> = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+    keys.iter().map(|x| x.to_string()).all(|key| passport_data.contains_key(&key))
+}
+
+fn is_valid_year(value: &str, range: std::ops::Range<i32>) -> bool {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^\d\d\d\d$").unwrap();
+    }
+
+    RE.find(value).is_some() && value.parse::<i32>().is_ok_and(|year| range.contains(&year))
+}
+
+fn is_valid_height(value: &str) -> bool {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\d+)(in|cm)$").unwrap();
+    }
+
+    let cap_opt = RE.captures(value);
+
+    if cap_opt.is_none() {
+        return false;
+    }
+
+    let cap = cap_opt.unwrap();
+
+    let range = match &cap[2] {
+        "in" => 59..77,
+        "cm" => 150..194,
+        _ => panic!("Invalid Height")
+    };
+
+    cap[1].parse::<i32>().is_ok_and(|height| range.contains(&height))
+}
+
+fn is_valid_hair_color(value: &str) -> bool {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^#[\da-f]{6}$").unwrap();
+    }
+
+    RE.find(value).is_some()
+}
+
+fn is_valid_eye_color(value: &str) -> bool {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(amb|blu|brn|gry|grn|hzl|oth)$").unwrap();
+    }
+
+    RE.find(value).is_some()
+}
+
+fn is_valid_pid(value: &str) -> bool {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^\d{9}$").unwrap();
+    }
+
+    RE.find(value).is_some()
+}
+
+fn is_valid_passport2(passport_data: &HashMap<String, String>) -> bool {
+    let keys : Vec<&str> = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+    if !keys.iter().map(|x| x.to_string()).all(|key| passport_data.contains_key(&key)) {
+        return false;
+    }
+
+    is_valid_year(&passport_data["byr"], 1920..2003) &&
+           is_valid_year(&passport_data["iyr"], 2010..2021) &&
+           is_valid_year(&passport_data["eyr"], 2020..2031) &&
+           is_valid_height(&passport_data["hgt"]) &&
+           is_valid_hair_color(&passport_data["hcl"]) &&
+           is_valid_eye_color(&passport_data["ecl"]) &&
+           is_valid_pid(&passport_data["pid"])
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<HashMap<String, String>>;
+
+    fn parse(input: &str) -> Result<Vec<HashMap<String, String>>> {
+        extract_passport_data(input)
+    }
+
+    fn part1(all_passport_data: &Vec<HashMap<String, String>>) -> Answer {
+        all_passport_data.iter().filter(|x| is_valid_passport1(x)).count().into()
+    }
+
+    fn part2(all_passport_data: &Vec<HashMap<String, String>>) -> Answer {
+        all_passport_data.iter().filter(|x| is_valid_passport2(x)).count().into()
+    }
+
+    fn details(all_passport_data: &Vec<HashMap<String, String>>, _part: u8) -> Details {
+        vec![("passports", all_passport_data.len().into())]
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_common::{parse_lines, Answer, Details, Error, Result, Solution};
+
+pub struct BordingPass {
+    _row: i32,
+    _column: i32,
+    id: i32,
+}
+
+fn decode(line: &str, code: &str, one: char, zero: char) -> Result<i32> {
+    code.char_indices().try_fold(0, |value, (i, c)| match c {
+        c if c == one => Ok(value*2 + 1),
+        c if c == zero => Ok(value*2),
+        _ => Err(Error::parse(line, &code[i..], format!("Bad Encoding, expected `{}` or `{}`", one, zero))),
+    })
+}
+
+fn parse_bording_pass(line: &str) -> Result<BordingPass> {
+    if line.len() != 10 || !line.is_ascii() {
+        return Err(Error::parse(line, line, "expected 7 row and 3 column characters"));
+    }
+
+    let row = decode(line, &line[..7], 'B', 'F')?;
+    let column = decode(line, &line[7..], 'R', 'L')?;
+
+    Ok(BordingPass {
+        _row: row, 
+        _column: column,
+        id: row*8 + column })
+}
+
+fn highest_seat(passes: &[BordingPass]) -> Option<i32> {
+    passes.iter().map(|x| x.id).max()
+}
+
+fn my_seat(passes: &[BordingPass]) -> Option<i32> {
+    let ids : Vec<i32> = passes.iter().map(|x| x.id).collect();
+
+    (0..1024).find(|id| (!ids.contains(id)) && ids.contains(&(id+1)) && ids.contains(&(id-1)))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<BordingPass>;
+
+    fn parse(input: &str) -> Result<Vec<BordingPass>> {
+        parse_lines(input, parse_bording_pass)
+    }
+
+    fn part1(passes: &Vec<BordingPass>) -> Answer {
+        highest_seat(passes).map_or(Answer::Unsolved, |id| id.into())
+    }
+
+    fn part2(passes: &Vec<BordingPass>) -> Answer {
+        my_seat(passes).map_or(Answer::Unsolved, |id| id.into())
+    }
+
+    fn details(passes: &Vec<BordingPass>, part: u8) -> Details {
+        let seat = if part == 1 { highest_seat(passes) } else { my_seat(passes) };
+
+        seat.map_or(Vec::new(), |id| vec![("row", (id / 8).into()), ("column", (id % 8).into())])
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_common::{records, Answer, Error, Result, Solution};
+use std::collections::HashSet;
+
+fn to_set(line: &str) -> HashSet<char> {
+    let mut answers = HashSet::new();
+    for c in line.chars() {
+        answers.insert(c);
+    }
+    answers
+}
+
+fn parse_answers(line: &str) -> Result<String> {
+    match line.find(|c: char| !c.is_ascii_lowercase()) {
+        Some(i) => Err(Error::parse(line, &line[i..], "expected answers `a` to `z`")),
+        None => Ok(line.to_string()),
+    }
+}
+
+fn extract_answers1(groups: &[Vec<String>]) -> Vec<HashSet<char>> {
+    let mut all_answers = Vec::new();
+
+    for lines in groups {
+        let answers = lines.iter().map(|x| to_set(x))
+            .fold(HashSet::new(), |x, y| x.union(&y).cloned().collect());
+
+        all_answers.push(answers);
+    }
+
+    all_answers
+}
+
+fn all_chars() -> HashSet<char>
+{
+    let set: HashSet<char> = "abcdefghijklmnopqrstuvwxyz".chars().collect();
+
+    set
+}
+
+fn extract_answers2(groups: &[Vec<String>]) -> Vec<HashSet<char>> {
+    let mut all_answers = Vec::new();
+
+    for lines in groups {
+        let answers = lines.iter().map(|x| to_set(x))
+            .fold(all_chars(), |x, y| x.intersection(&y).cloned().collect());
+
+        all_answers.push(answers);
+    }
+
+    all_answers
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Vec<String>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<String>>> {
+        records(input.as_bytes())
+            .map(|record| record?.parse_lines(parse_answers))
+            .collect()
+    }
+
+    fn part1(groups: &Vec<Vec<String>>) -> Answer {
+        let all_answers1 = extract_answers1(groups);
+        all_answers1.iter().map(|x| x.len()).sum::<usize>().into()
+    }
+
+    fn part2(groups: &Vec<Vec<String>>) -> Answer {
+        let all_answers2 = extract_answers2(groups);
+        all_answers2.iter().map(|x| x.len()).sum::<usize>().into()
+    }
+}
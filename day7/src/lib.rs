@@ -0,0 +1,145 @@
+#[macro_use] extern crate lazy_static;
+use aoc_common::{parse_lines, parse_number, Answer, Error, Result, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+struct Bag {
+    bag_name: String,
+    contains: Vec<(u32, String)>,
+    contained_in: Vec<String>
+}
+
+impl Bag {
+    fn new(bag_name: String ) -> Bag {
+        Bag{ bag_name,
+            contains: Vec::new(),
+            contained_in: Vec::new(),
+        }
+    }
+}
+
+pub struct BagTracker {
+    bag_map: HashMap<String, Bag>,
+}
+
+impl BagTracker {
+    fn new() -> BagTracker {
+        BagTracker{ bag_map: HashMap::new() }
+    }
+
+    fn add_bag(&mut self, bag_name: &str)
+    {
+        if !self.bag_map.contains_key(bag_name) {
+            self.bag_map.insert(
+                bag_name.to_string(),
+                Bag::new(bag_name.to_string()));
+        }
+    }
+
+    fn add_bag_relationship(&mut self, src_bag_name: &str, dst_bag_name: &str, quantity: u32)
+    {
+        self.add_bag(src_bag_name);
+        self.add_bag(dst_bag_name);
+
+        self.bag_map.get_mut(src_bag_name).unwrap().contains.push((quantity, dst_bag_name.to_string()));
+        self.bag_map.get_mut(dst_bag_name).unwrap().contained_in.push(src_bag_name.to_string());
+    }
+
+    fn get_bag(&self, bag_name: &str) -> &Bag
+    {
+        &self.bag_map[bag_name]
+    }
+
+    fn get_parents(&self, bag_name: &str) -> Vec<&Bag> {
+        self.get_bag(bag_name).contained_in.iter().map(|x| self.get_bag(x)).collect()
+    }
+
+    fn get_all_parents(&self, bag_name: &str) -> Vec <&Bag> {
+        self.get_parents(bag_name)
+            .iter().copied()
+            .chain(
+                self.get_parents(bag_name)
+                .iter()
+                .flat_map(|x|self.get_all_parents(&x.bag_name)))
+            .collect()
+    }
+
+    fn get_children(&self, bag_name: &str) -> Vec<(u32, &Bag)> {
+        self.get_bag(bag_name).contains.iter().map(|(x,y)| (*x, self.get_bag(y))).collect()
+    }
+
+    fn get_all_children(&self, bag_name: &str) -> Vec<(u32, &Bag)> {
+
+        let mut chidren = self.get_children(bag_name);
+        for (count, child) in self.get_children(bag_name)
+        {
+            let sub_children : Vec<(u32, &Bag)> = self.get_all_children(&child.bag_name).iter().map(|(x,y)|(count*x,*y)).collect();
+            chidren.extend(sub_children);
+        }
+
+        chidren
+    }
+}
+
+fn parse_relationships(line: &str, src_bag_name: &str, relationships: &str, bags: &mut BagTracker) -> Result<()>
+{
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"(\d+) (\D+) bag[s]?").unwrap();
+    }
+
+    if relationships == "no other bags" {
+        return Ok(());
+    }
+
+    if !RE.is_match(relationships) {
+        return Err(Error::parse(line, relationships, "expected `no other bags` or `<count> <colour> bags`"));
+    }
+
+    for cap in RE.captures_iter(relationships) {
+        let quantity = parse_number(line, cap.get(1).unwrap().as_str())?;
+        bags.add_bag_relationship(src_bag_name, &cap[2], quantity)
+    }
+    Ok(())
+}
+
+fn parse_bag_rule(line: &str, bags: &mut BagTracker) -> Result<()>
+{
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^([\w ]+) bags contain (.+)\.$").unwrap();
+    }
+
+    let cap = RE.captures(line)
+        .ok_or_else(|| Error::parse(line, line, "expected `<colour> bags contain <contents>.`"))?;
+
+    let bag_name = cap.get(1).unwrap().as_str();
+    let relationships = cap.get(2).unwrap().as_str();
+    bags.add_bag(bag_name);
+
+    parse_relationships(line, bag_name, relationships, bags)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = BagTracker;
+
+    fn parse(input: &str) -> Result<BagTracker> {
+        let mut bags = BagTracker::new();
+
+        parse_lines(input, |line| parse_bag_rule(line, &mut bags))?;
+        Ok(bags)
+    }
+
+    fn part1(bags: &BagTracker) -> Answer {
+        let parents : HashSet<_> = bags.get_all_parents("shiny gold").into_iter().map(|x| x.bag_name.to_string()).collect();
+
+        parents.len().into()
+    }
+
+    fn part2(bags: &BagTracker) -> Answer {
+        let children : u32 = bags.get_all_children("shiny gold").into_iter().map(|(count, _)| count).sum();
+
+        children.into()
+    }
+}
@@ -0,0 +1,110 @@
+#[macro_use] extern crate lazy_static;
+use aoc_common::{parse_lines, parse_number, Answer, Details, Error, Solution};
+use regex::Regex;
+
+#[derive(Copy, Clone)]
+struct MachineState {
+    acc: i32,
+    pc: i32,
+}
+
+#[derive(Copy, Clone)]
+pub enum Operation {
+    Acc{ value: i32},
+    Jmp{ offset: i32},
+    Nop{ value: i32},
+}
+
+fn parse_line(line: &str) -> aoc_common::Result<Operation> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\w+) ([-+]\d+)$").unwrap();
+    }
+
+    let cap = RE.captures(line)
+        .ok_or_else(|| Error::parse(line, line, "expected `<opcode> <+/-value>`"))?;
+
+    let opcode = cap.get(1).unwrap().as_str();
+    let value = parse_number(line, cap.get(2).unwrap().as_str())?;
+
+    match opcode {
+        "nop" => Ok(Operation::Nop{value}),
+        "acc" => Ok(Operation::Acc{value}),
+        "jmp" => Ok(Operation::Jmp{offset:value}),
+        _ => Err(Error::parse(line, opcode, format!("Unknown Opcode `{}`", opcode))),
+    }
+}
+
+fn run_cycle(state: &MachineState, opcode: Operation) -> MachineState {
+    match opcode {
+        Operation::Acc{value} => MachineState{     acc: state.acc + value,
+                                                   pc: state.pc +1},
+        Operation::Jmp{offset} => MachineState{    acc: state.acc,
+                                                   pc: state.pc +offset},
+        Operation::Nop{value:_}  => MachineState{  acc: state.acc,
+                                                   pc: state.pc +1},                                               
+    }
+}
+
+fn simulate( program: &[Operation] ) -> Result<i32, i32>{
+    let mut trace : Vec<(Operation, bool)> = program.iter().map(|x| (*x, false)).collect();
+    let mut state = MachineState{acc:0,pc:0};
+
+    loop{
+        if state.pc as usize == trace.len() {
+            return Ok(state.acc);
+        }
+        let (opcode, hit) = trace[state.pc as usize];
+        if hit {
+            return Err(state.acc);
+        }
+        trace[state.pc as usize] = (opcode, true);
+        state=run_cycle(&state,opcode);
+    }
+}
+
+/// Swaps one `jmp`/`nop` so the program terminates, returning the index of the swapped
+/// instruction and the final accumulator.
+fn fix_program(program: &[Operation]) -> Option<(usize, i32)> {
+    for i in 0..program.len(){
+        let mut copy = program.to_vec();
+        match program[i] {
+            Operation::Acc{value: _} => continue,
+            Operation::Jmp{offset} => copy[i] = Operation::Nop{value:offset},
+            Operation::Nop{value}  => copy[i] = Operation::Jmp{offset:value},
+        }
+
+        match simulate(&copy) {
+            Ok(acc) => return Some((i, acc)),
+            Err(_) => continue
+        }
+    }
+    None
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> aoc_common::Result<Vec<Operation>> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part1(program: &Vec<Operation>) -> Answer {
+        match simulate(program) {
+            Ok(_) => Answer::Unsolved,
+            Err(acc) => acc.into(),
+        }
+    }
+
+    fn part2(program: &Vec<Operation>) -> Answer {
+        fix_program(program).map_or(Answer::Unsolved, |(_, acc)| acc.into())
+    }
+
+    fn details(program: &Vec<Operation>, part: u8) -> Details {
+        match (part, fix_program(program)) {
+            (2, Some((i, _))) => vec![("patched_line", (i + 1).into())],
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_common::{parse_lines, parse_number, Answer, Error, Result, Solution};
+use std::collections::VecDeque;
+
+/// How many values come before the first one checked against them.
+const PREAMBLE: usize = 25;
+
+fn find_invalid(values: &[i64]) -> Option<i64> {
+    let mut preamble = VecDeque::<i64>::new();
+
+    preamble.extend(values[..PREAMBLE].iter());
+
+    for value in values[PREAMBLE..].iter() {
+        let mut valid = false;
+        for i in 0..PREAMBLE - 1 {
+            let part1 = preamble[i];
+            for part2 in preamble.iter().skip(i+1) {
+                if part1.checked_add(*part2) == Some(*value) {
+                    valid = true;
+                }
+            }
+        }
+
+        if !valid {
+            return Some(*value);
+        }
+
+        preamble.push_back(*value);
+        preamble.pop_front();
+    }
+    None
+}
+
+fn find_weakness(values: &[i64], magic_value: i64) -> Option<i64> {
+    for start in 0..values.len(){
+        let mut acc = magic_value as i128 - values[start] as i128;
+        let mut end = start+1;
+
+        while end < values.len() && acc > 0
+        {
+            acc -= values[end] as i128;
+            end += 1;
+        }
+
+        if acc == 0
+        {
+            let weakness = values[start..end].to_vec();
+            let min = weakness.iter().min().unwrap();
+            let max = weakness.iter().max().unwrap();
+            return Some(*min + *max);
+        }
+
+    }
+    None
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        let values = parse_lines(input, |line| parse_number(line, line))?;
+        if values.len() <= PREAMBLE {
+            let message = format!("expected a preamble of {} values and more after it, found {}", PREAMBLE, values.len());
+            return Err(Error::parse("", "", message).offset_lines(input.lines().count()));
+        }
+        Ok(values)
+    }
+
+    fn part1(values: &Vec<i64>) -> Answer {
+        find_invalid(values).map_or(Answer::Unsolved, |x| x.into())
+    }
+
+    fn part2(values: &Vec<i64>) -> Answer {
+        find_invalid(values).and_then(|x| find_weakness(values, x)).map_or(Answer::Unsolved, |x| x.into())
+    }
+}
@@ -0,0 +1,10 @@
+use aoc::days::get_day;
+use aoc_common::Error;
+
+/// Parses `input` as `day`'s puzzle input, which must give the input or a parse error.
+pub fn check_parse(day: u8, input: &str) {
+    match get_day(day).unwrap().solver.parse(input) {
+        Ok(_) | Err(Error::Parse(_)) => (),
+        Err(error) => panic!("day {}: expected a parse error, got {}", day, error),
+    }
+}